@@ -0,0 +1,188 @@
+use common::visualize::{Color, Frame, Simulation};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{self, Attribute, Print, ResetColor, SetAttribute, SetForegroundColor};
+use crossterm::{cursor, queue, terminal};
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+/// Keys controlling the animation, shown below the status line.
+const HELP: &str = "space: pause/resume  right: step  +/-: faster/slower  q: quit";
+
+/// Action requested by a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    TogglePause,
+    Step,
+    Faster,
+    Slower,
+}
+
+impl Action {
+    /// Returns the action bound to `key`, if any.
+    pub fn from_key(key: &KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Action::Quit)
+            }
+            KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
+            KeyCode::Char(' ') | KeyCode::Char('p') => Some(Action::TogglePause),
+            KeyCode::Right | KeyCode::Char('n') | KeyCode::Char('s') => Some(Action::Step),
+            KeyCode::Char('+') | KeyCode::Char('=') => Some(Action::Faster),
+            KeyCode::Char('-') => Some(Action::Slower),
+            _ => None,
+        }
+    }
+}
+
+/// Switches the terminal to an alternate screen in raw mode, restoring it when dropped.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        terminal::enable_raw_mode()?;
+        queue!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = queue!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = io::stdout().flush();
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn terminal_color(color: Color) -> style::Color {
+    match color {
+        Color::Default => style::Color::Reset,
+        Color::Dim => style::Color::DarkGrey,
+        Color::Red => style::Color::Red,
+        Color::Green => style::Color::Green,
+        Color::Yellow => style::Color::Yellow,
+        Color::Blue => style::Color::Blue,
+        Color::Magenta => style::Color::Magenta,
+        Color::Cyan => style::Color::Cyan,
+        Color::White => style::Color::White,
+    }
+}
+
+/// Draws `frame` below the status and help lines, cropped to the size of the terminal.
+fn draw(out: &mut impl Write, frame: &Frame, state: &str) -> io::Result<()> {
+    let (columns, rows) = terminal::size()?;
+    queue!(
+        out,
+        cursor::MoveTo(0, 0),
+        terminal::Clear(terminal::ClearType::All),
+        SetAttribute(Attribute::Bold),
+        Print(crop(&frame.status, columns)),
+        SetAttribute(Attribute::Reset),
+        cursor::MoveTo(0, 1),
+        Print(crop(&format!("{}  [{}]", HELP, state), columns)),
+    )?;
+
+    for (y, row) in frame
+        .rows
+        .iter()
+        .take((rows as usize).saturating_sub(2))
+        .enumerate()
+    {
+        queue!(out, cursor::MoveTo(0, y as u16 + 2))?;
+        let mut current = None;
+        for &(c, color) in row.iter().take(columns as usize) {
+            if current != Some(color) {
+                queue!(out, SetForegroundColor(terminal_color(color)))?;
+                current = Some(color);
+            }
+            queue!(out, Print(c))?;
+        }
+    }
+    queue!(out, ResetColor)?;
+    out.flush()
+}
+
+/// Returns at most the first `columns` characters of `text`.
+fn crop(text: &str, columns: u16) -> String {
+    text.chars().take(columns as usize).collect()
+}
+
+/// Waits up to `timeout`, or indefinitely if it is `None`, for a key bound to an action.
+fn next_action(timeout: Option<Duration>) -> io::Result<Option<Action>> {
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    loop {
+        let remaining = match deadline {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => Duration::from_secs(3600),
+        };
+        if !event::poll(remaining)? {
+            if deadline.is_some() {
+                return Ok(None);
+            }
+            continue;
+        }
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                if let Some(action) = Action::from_key(&key) {
+                    return Ok(Some(action));
+                }
+            }
+        }
+    }
+}
+
+/// Animates `simulation` in the terminal, advancing it every `delay` unless paused. When
+/// standard output is not a terminal, all states are printed one after another instead.
+pub fn animate(simulation: &mut dyn Simulation, delay: Duration, paused: bool) -> io::Result<()> {
+    let mut out = io::stdout();
+    if !out.is_terminal() {
+        loop {
+            let frame = simulation.render();
+            writeln!(out, "{}\n{}", frame.status, frame.to_plain_text())?;
+            if !simulation.step() {
+                return Ok(());
+            }
+        }
+    }
+
+    let _screen = Screen::enter()?;
+    let (mut delay, mut paused, mut finished) = (delay, paused, false);
+    loop {
+        let state = match (finished, paused) {
+            (true, _) => "finished".to_owned(),
+            (false, true) => "paused".to_owned(),
+            (false, false) => format!("every {:?}", delay),
+        };
+        draw(&mut out, &simulation.render(), &state)?;
+
+        let timeout = if paused || finished {
+            None
+        } else {
+            Some(delay)
+        };
+        match next_action(timeout)? {
+            Some(Action::Quit) => return Ok(()),
+            Some(Action::TogglePause) => paused = !paused,
+            Some(Action::Step) => {
+                paused = true;
+                finished = !simulation.step();
+            }
+            Some(Action::Faster) => delay = (delay / 2).max(Duration::from_millis(1)),
+            Some(Action::Slower) => delay = (delay * 2).min(Duration::from_secs(10)),
+            None => finished = !simulation.step(),
+        }
+    }
+}
+
+#[test]
+fn test_actions() {
+    let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    assert_eq!(
+        Action::from_key(&key(KeyCode::Char('q'))),
+        Some(Action::Quit)
+    );
+    assert_eq!(Action::from_key(&key(KeyCode::Right)), Some(Action::Step));
+    assert_eq!(Action::from_key(&key(KeyCode::Char('x'))), None);
+    let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+    assert_eq!(Action::from_key(&ctrl_c), Some(Action::Quit));
+}
@@ -0,0 +1,99 @@
+use common::{ParseError, Puzzle};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// Minimum, median and maximum of the times measured for one phase, in nanoseconds.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    /// Summarizes the given samples, which must not be empty.
+    pub fn from_samples(samples: &mut [Duration]) -> Stats {
+        samples.sort();
+        let nanos = |d: Duration| d.as_nanos() as u64;
+        Stats {
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(samples[samples.len() / 2]),
+            max_ns: nanos(samples[samples.len() - 1]),
+        }
+    }
+}
+
+/// Timings of the phases of a single day.
+#[derive(Debug, Clone, Serialize)]
+pub struct DayTimings {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Timings of all benchmarked days, as emitted with `--json`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayTimings>,
+}
+
+/// Times parsing `text` and solving both parts on it `iterations` times each.
+pub fn bench(puzzle: &dyn Puzzle, text: &str, iterations: usize) -> Result<DayTimings, ParseError> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = puzzle.parse(text)?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        std::hint::black_box(parsed.part1());
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        std::hint::black_box(parsed.part2());
+        part2.push(start.elapsed());
+    }
+
+    Ok(DayTimings {
+        day: puzzle.day(),
+        parse: Stats::from_samples(&mut parse),
+        part1: Stats::from_samples(&mut part1),
+        part2: Stats::from_samples(&mut part2),
+    })
+}
+
+/// Prints the report as a table with one row per day and phase.
+pub fn print_table(report: &Report) {
+    println!(
+        "{:<4} {:<6} {:>12} {:>12} {:>12}",
+        "day", "phase", "min", "median", "max"
+    );
+    for timings in &report.days {
+        for (phase, stats) in [
+            ("parse", &timings.parse),
+            ("part 1", &timings.part1),
+            ("part 2", &timings.part2),
+        ] {
+            println!(
+                "{:<4} {:<6} {:>12} {:>12} {:>12}",
+                format!("{:02}", timings.day),
+                phase,
+                format!("{:.1?}", Duration::from_nanos(stats.min_ns)),
+                format!("{:.1?}", Duration::from_nanos(stats.median_ns)),
+                format!("{:.1?}", Duration::from_nanos(stats.max_ns)),
+            );
+        }
+    }
+}
+
+#[test]
+fn test_stats() {
+    let mut samples = [3, 1, 4, 1, 5].map(Duration::from_nanos);
+    let stats = Stats::from_samples(&mut samples);
+    assert_eq!((stats.min_ns, stats.median_ns, stats.max_ns), (1, 3, 5));
+}
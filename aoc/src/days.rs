@@ -0,0 +1,168 @@
+use common::svg::Svg;
+use common::visualize::Simulation;
+use common::{puzzle, ParseError, Puzzle};
+use rand::rngs::StdRng;
+
+/// Returns the puzzles of all days that have a solution in this workspace.
+pub fn all() -> Vec<&'static dyn Puzzle> {
+    vec![
+        puzzle::<day01::Day01>(),
+        puzzle::<day02::Day02>(),
+        puzzle::<day03::Day03>(),
+        puzzle::<day04::Day04>(),
+        puzzle::<day05::Day05>(),
+        puzzle::<day06::Day06>(),
+        puzzle::<day07::Day07>(),
+        puzzle::<day08::Day08>(),
+        puzzle::<day09::Day09>(),
+        puzzle::<day10::Day10>(),
+        puzzle::<day11::Day11>(),
+        puzzle::<day12::Day12>(),
+        puzzle::<day13::Day13>(),
+        puzzle::<day14::Day14>(),
+        puzzle::<day15::Day15>(),
+        puzzle::<day16::Day16>(),
+        puzzle::<day17::Day17>(),
+        puzzle::<day18::Day18>(),
+        puzzle::<day19::Day19>(),
+        puzzle::<day20::Day20>(),
+        puzzle::<day21::Day21>(),
+        puzzle::<day22::Day22>(),
+        puzzle::<day23::Day23>(),
+        puzzle::<day24::Day24>(),
+        puzzle::<day25::Day25>(),
+    ]
+}
+
+/// Returns the puzzle of `day` if it has a solution.
+pub fn get(day: u8) -> Option<&'static dyn Puzzle> {
+    all().into_iter().find(|p| p.day() == day)
+}
+
+/// Generates a random input of a day from a seeded random number generator and a scale.
+pub type Generator = fn(&mut StdRng, usize) -> String;
+
+/// Returns the input generators of all days that have a solution in this workspace.
+pub fn generators() -> Vec<(u8, Generator)> {
+    vec![
+        (1, day01::generator::generate),
+        (2, day02::generator::generate),
+        (3, day03::generator::generate),
+        (4, day04::generator::generate),
+        (5, day05::generator::generate),
+        (6, day06::generator::generate),
+        (7, day07::generator::generate),
+        (8, day08::generator::generate),
+        (9, day09::generator::generate),
+        (10, day10::generator::generate),
+        (11, day11::generator::generate),
+        (12, day12::generator::generate),
+        (13, day13::generator::generate),
+        (14, day14::generator::generate),
+        (15, day15::generator::generate),
+        (16, day16::generator::generate),
+        (17, day17::generator::generate),
+        (18, day18::generator::generate),
+        (19, day19::generator::generate),
+        (20, day20::generator::generate),
+        (21, day21::generator::generate),
+        (22, day22::generator::generate),
+        (23, day23::generator::generate),
+        (24, day24::generator::generate),
+        (25, day25::generator::generate),
+    ]
+}
+
+/// Returns the input generator of `day` if it has a solution.
+pub fn generator(day: u8) -> Option<Generator> {
+    generators()
+        .into_iter()
+        .find(|&(d, _)| d == day)
+        .map(|(_, generator)| generator)
+}
+
+/// Creates the step by step simulation of a day from its puzzle input.
+pub type Visualizer = fn(&str) -> Result<Box<dyn Simulation>, ParseError>;
+
+/// Returns the simulations of the days whose solution can be animated.
+pub fn visualizers() -> Vec<(u8, Visualizer)> {
+    vec![
+        (6, day06::visualize::simulation),
+        (11, day11::visualize::simulation),
+        (13, day13::visualize::simulation),
+        (17, day17::visualize::simulation),
+        (20, day20::visualize::simulation),
+    ]
+}
+
+/// Returns the simulation of `day` if it can be animated.
+pub fn visualizer(day: u8) -> Option<Visualizer> {
+    visualizers()
+        .into_iter()
+        .find(|&(d, _)| d == day)
+        .map(|(_, visualizer)| visualizer)
+}
+
+/// Draws a picture of a day from its puzzle input.
+pub type Drawing = fn(&str) -> Result<Svg, ParseError>;
+
+/// Returns the drawings of the days whose puzzle can be exported as a picture.
+pub fn drawings() -> Vec<(u8, Drawing)> {
+    vec![
+        (5, day05::draw::drawing),
+        (13, day13::draw::drawing),
+        (15, day15::draw::drawing),
+        (17, day17::draw::drawing),
+        (22, day22::draw::drawing),
+    ]
+}
+
+/// Returns the drawing of `day` if it can be exported as a picture.
+pub fn drawing(day: u8) -> Option<Drawing> {
+    drawings()
+        .into_iter()
+        .find(|&(d, _)| d == day)
+        .map(|(_, drawing)| drawing)
+}
+
+#[test]
+fn test_generators() {
+    use rand::SeedableRng;
+
+    for (day, generate) in generators() {
+        let text = generate(&mut StdRng::seed_from_u64(7), 1);
+        assert_eq!(text, generate(&mut StdRng::seed_from_u64(7), 1));
+        if let Err(err) = get(day).unwrap().parse(&text) {
+            panic!("day {}: {}", day, err.diagnostic(&text));
+        }
+    }
+}
+
+#[test]
+fn test_visualizers() {
+    use rand::SeedableRng;
+
+    for (day, simulation) in visualizers() {
+        let text = generator(day).unwrap()(&mut StdRng::seed_from_u64(7), 1);
+        let mut simulation = simulation(&text).unwrap();
+        let first = simulation.render();
+        assert!(simulation.step(), "day {} has no steps", day);
+        assert_ne!(simulation.render(), first, "day {} did not change", day);
+    }
+}
+
+#[test]
+fn test_drawings() {
+    use rand::SeedableRng;
+
+    for (day, drawing) in drawings() {
+        let text = generator(day).unwrap()(&mut StdRng::seed_from_u64(7), 1);
+        let svg = drawing(&text).unwrap().to_string();
+        assert!(svg.starts_with("<svg "), "day {} drew {}", day, svg);
+        assert!(
+            svg.trim_end().ends_with("</svg>"),
+            "day {} is incomplete",
+            day
+        );
+    }
+}
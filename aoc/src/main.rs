@@ -0,0 +1,451 @@
+use clap::{Parser, Subcommand};
+use common::solution::print_answer;
+use common::{answer, Input, Parsed, Puzzle};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::path::PathBuf;
+use std::time::Duration;
+use verify::{Answers, Outcome};
+
+mod animate;
+mod bench;
+mod days;
+mod parallel;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one or all days and print their answers
+    Run {
+        /// Day to run, runs all days if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part to run, runs both parts if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file or `-` for stdin, defaults to $AOC_INPUT or inputs/dayNN.txt
+        #[arg(long, requires = "day")]
+        input: Option<String>,
+        /// Print the answers and their intermediate values as JSON
+        #[arg(long)]
+        json: bool,
+        /// Run the days concurrently and print a table of answers and times, reporting
+        /// days that fail instead of stopping at them
+        #[arg(long, conflicts_with = "json")]
+        parallel: bool,
+    },
+    /// Time parsing and both parts of one or all days
+    Bench {
+        /// Day to benchmark, benchmarks all days if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Input file or `-` for stdin, defaults to $AOC_INPUT or inputs/dayNN.txt
+        #[arg(long, requires = "day")]
+        input: Option<String>,
+        /// Number of times each phase is run
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Print the timings as JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check the answers of one or all days on their default inputs against the expected ones
+    Verify {
+        /// Day to verify, verifies all days if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// File of expected answers, defaults to answers.json next to the default inputs
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Store the current answers as the expected ones instead of checking them
+        #[arg(long)]
+        record: bool,
+    },
+    /// Print a random input of a day, for stress testing its solution
+    Generate {
+        /// Day to generate an input for
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Seed of the random number generator, a random one is used and reported if omitted
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Size of the input, 1 being about the size of the puzzle input
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        scale: u64,
+    },
+    /// Animate the simulation of a day step by step in the terminal
+    Visualize {
+        /// Day to animate, one of 6, 11, 13, 17 and 20
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Input file or `-` for stdin, defaults to $AOC_INPUT or inputs/dayNN.txt
+        #[arg(long)]
+        input: Option<String>,
+        /// Milliseconds between two steps
+        #[arg(long, default_value_t = 100)]
+        delay: u64,
+        /// Start paused, only advancing on key presses
+        #[arg(long)]
+        paused: bool,
+    },
+    /// Export a picture of the puzzle of a day as an SVG file
+    Draw {
+        /// Day to draw, one of 5, 13, 15, 17 and 22
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Input file or `-` for stdin, defaults to $AOC_INPUT or inputs/dayNN.txt
+        #[arg(long)]
+        input: Option<String>,
+        /// File to write the picture to
+        #[arg(long)]
+        output: PathBuf,
+    },
+}
+
+/// Returns the puzzle of `day` or of all days, exiting if `day` is not solved.
+fn select_puzzles(day: Option<u8>) -> Vec<&'static dyn Puzzle> {
+    match day {
+        Some(day) => match days::get(day) {
+            Some(puzzle) => vec![puzzle],
+            None => {
+                eprintln!("error: day {} is not solved yet", day);
+                std::process::exit(1);
+            }
+        },
+        None => days::all(),
+    }
+}
+
+/// Reads the input of `puzzle` given on the command line, or its default input, exiting if
+/// it cannot be read. `$AOC_INPUT` is only used when a single day is selected. When all
+/// days are selected, days without an input file are skipped by returning `None`.
+fn read_input(puzzle: &dyn Puzzle, arg: Option<&str>, single_day: bool) -> Option<String> {
+    let day = puzzle.day();
+    let input = match arg {
+        Some(arg) => Input::parse(arg),
+        None if single_day => Input::from_env(day),
+        None => Input::default_for(day),
+    };
+    match input.read() {
+        Ok(text) => Some(text),
+        Err(err) if !single_day && err.kind() == std::io::ErrorKind::NotFound => {
+            eprintln!("day {:02}: skipped, {} does not exist", day, input);
+            None
+        }
+        Err(err) => {
+            eprintln!("error: failed to read {}: {}", input, err);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Parses the input of `puzzle`, exiting with a diagnostic if it is malformed.
+fn parse(puzzle: &dyn Puzzle, text: &str) -> Box<dyn Parsed> {
+    match puzzle.parse(text) {
+        Ok(parsed) => parsed,
+        Err(err) => exit_on_parse_error(puzzle, text, err),
+    }
+}
+
+fn exit_on_parse_error(puzzle: &dyn Puzzle, text: &str, err: common::ParseError) -> ! {
+    eprintln!(
+        "error: failed to parse input of day {:02}: {}",
+        puzzle.day(),
+        err.diagnostic(text)
+    );
+    std::process::exit(1);
+}
+
+/// Prints the answers of `part`, or both parts, of the selected days.
+fn run(day: Option<u8>, part: Option<u8>, input: Option<String>, json: bool, parallel: bool) {
+    let puzzles = select_puzzles(day);
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let single_day = puzzles.len() == 1;
+    if parallel {
+        let days: Vec<_> = puzzles
+            .into_iter()
+            .filter_map(|puzzle| Some((puzzle, read_input(puzzle, input.as_deref(), single_day)?)))
+            .collect();
+        run_parallel(&days, &parts);
+        return;
+    }
+
+    let mut documents = Vec::new();
+    for puzzle in puzzles {
+        let Some(text) = read_input(puzzle, input.as_deref(), single_day) else {
+            continue;
+        };
+        let parsed = parse(puzzle, &text);
+        for &part in &parts {
+            let answer = parsed.answer(part).expect("part is either 1 or 2");
+            if json {
+                documents.push(answer::to_json(puzzle.day(), part, answer.as_ref()));
+            } else {
+                let label = format!("day {:02} part {}", puzzle.day(), part);
+                print_answer(&label, &answer.to_string());
+            }
+        }
+    }
+
+    if json {
+        let json = serde_json::to_string_pretty(&documents).expect("answers are serializable");
+        println!("{}", json);
+    }
+}
+
+/// Runs `days` concurrently and prints the summary table, exiting with an error if any
+/// of them failed.
+fn run_parallel(days: &[(&'static dyn Puzzle, String)], parts: &[u8]) {
+    // Panics are reported in the table, not by the default hook. It is replaced before any
+    // day runs, as swapping it while other threads panic would race with them.
+    std::panic::set_hook(Box::new(|_| {}));
+    let start = std::time::Instant::now();
+    let results = parallel::run_all(days, parts);
+    let elapsed = start.elapsed();
+
+    parallel::print_table(&results);
+    let failed = results.iter().filter(|r| r.failed()).count();
+    println!(
+        "{} days in {:.1?}, {} failed",
+        results.len(),
+        elapsed,
+        failed
+    );
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Times the selected days and prints the report as a table or as JSON.
+fn bench(day: Option<u8>, input: Option<String>, iterations: u32, json: bool) {
+    let puzzles = select_puzzles(day);
+    let single_day = puzzles.len() == 1;
+    let mut report = bench::Report {
+        iterations: iterations as usize,
+        days: Vec::new(),
+    };
+    for puzzle in puzzles {
+        let Some(text) = read_input(puzzle, input.as_deref(), single_day) else {
+            continue;
+        };
+        match bench::bench(puzzle, &text, iterations as usize) {
+            Ok(timings) => report.days.push(timings),
+            Err(err) => exit_on_parse_error(puzzle, &text, err),
+        }
+    }
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).expect("report is serializable")
+        );
+    } else {
+        bench::print_table(&report);
+    }
+}
+
+/// Checks the answers of the selected days against the expected ones, or records them.
+fn verify(day: Option<u8>, answers: Option<PathBuf>, record: bool) {
+    let path = answers.unwrap_or_else(verify::default_path);
+    let mut answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: failed to load {}: {}", path.display(), err);
+            std::process::exit(1);
+        }
+    };
+
+    let (mut passed, mut failed, mut unknown, mut skipped) = (0, 0, 0, 0);
+    for puzzle in select_puzzles(day) {
+        let day = puzzle.day();
+        let input = Input::default_for(day);
+        let parsed = match input.read() {
+            Ok(text) => match puzzle.parse(&text) {
+                Ok(parsed) => parsed,
+                Err(err) => {
+                    println!("day {:02}: error: failed to parse {}: {}", day, input, err);
+                    failed += 2;
+                    continue;
+                }
+            },
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                println!("day {:02}: skipped, {} does not exist", day, input);
+                skipped += 1;
+                continue;
+            }
+            Err(err) => {
+                println!("day {:02}: error: failed to read {}: {}", day, input, err);
+                failed += 2;
+                continue;
+            }
+        };
+
+        for part in [1, 2] {
+            let label = format!("day {:02} part {}", day, part);
+            let actual = parsed.part(part).expect("part is either 1 or 2");
+            if record {
+                answers.day_mut(day).set(part, actual);
+                continue;
+            }
+
+            let expected = answers.day(day).and_then(|a| a.get(part));
+            match Outcome::check(expected, actual) {
+                Outcome::Pass => {
+                    passed += 1;
+                    println!("{}: pass", label);
+                }
+                Outcome::Mismatch { expected, actual } => {
+                    failed += 1;
+                    println!("{}: mismatch", label);
+                    print!("{}", verify::diff(&expected, &actual));
+                }
+                Outcome::Unknown { actual } => {
+                    unknown += 1;
+                    print_answer(&format!("{}: no expected answer, got", label), &actual);
+                }
+            }
+        }
+    }
+
+    if record {
+        if let Err(err) = answers.save(&path) {
+            eprintln!("error: failed to write {}: {}", path.display(), err);
+            std::process::exit(1);
+        }
+        println!("recorded answers in {}", path.display());
+    } else {
+        println!(
+            "{} passed, {} failed, {} without expected answer, {} without input",
+            passed, failed, unknown, skipped
+        );
+    }
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Prints a random input of `day` generated from `seed`.
+fn generate(day: u8, seed: Option<u64>, scale: u64) {
+    let generate = match days::generator(day) {
+        Some(generate) => generate,
+        None => {
+            eprintln!("error: day {} is not solved yet", day);
+            std::process::exit(1);
+        }
+    };
+    let seed = seed.unwrap_or_else(|| {
+        let seed = rand::random();
+        eprintln!("seed: {}", seed);
+        seed
+    });
+
+    print!(
+        "{}",
+        generate(&mut StdRng::seed_from_u64(seed), scale as usize)
+    );
+}
+
+/// Animates the simulation of `day` on its input.
+fn visualize(day: u8, input: Option<String>, delay: u64, paused: bool) {
+    let simulation = match days::visualizer(day) {
+        Some(simulation) => simulation,
+        None => {
+            let days: Vec<_> = days::visualizers()
+                .iter()
+                .map(|(day, _)| day.to_string())
+                .collect();
+            eprintln!(
+                "error: day {} cannot be animated, only days {} can",
+                day,
+                days.join(", ")
+            );
+            std::process::exit(1);
+        }
+    };
+    let puzzle = select_puzzles(Some(day))[0];
+    let text = read_input(puzzle, input.as_deref(), true).expect("single day input is read");
+    let mut simulation = match simulation(&text) {
+        Ok(simulation) => simulation,
+        Err(err) => exit_on_parse_error(puzzle, &text, err),
+    };
+
+    if let Err(err) = animate::animate(simulation.as_mut(), Duration::from_millis(delay), paused) {
+        eprintln!("error: failed to animate day {}: {}", day, err);
+        std::process::exit(1);
+    }
+}
+
+/// Draws the puzzle of `day` on its input and writes the picture to `output`.
+fn draw(day: u8, input: Option<String>, output: PathBuf) {
+    let drawing = match days::drawing(day) {
+        Some(drawing) => drawing,
+        None => {
+            let days: Vec<_> = days::drawings()
+                .iter()
+                .map(|(day, _)| day.to_string())
+                .collect();
+            eprintln!(
+                "error: day {} cannot be drawn, only days {} can",
+                day,
+                days.join(", ")
+            );
+            std::process::exit(1);
+        }
+    };
+    let puzzle = select_puzzles(Some(day))[0];
+    let text = read_input(puzzle, input.as_deref(), true).expect("single day input is read");
+    let svg = match drawing(&text) {
+        Ok(svg) => svg,
+        Err(err) => exit_on_parse_error(puzzle, &text, err),
+    };
+
+    if let Err(err) = svg.save(&output) {
+        eprintln!("error: failed to write {}: {}", output.display(), err);
+        std::process::exit(1);
+    }
+    println!("drew day {:02} to {}", day, output.display());
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            json,
+            parallel,
+        } => run(day, part, input, json, parallel),
+        Command::Bench {
+            day,
+            input,
+            iterations,
+            json,
+        } => bench(day, input, iterations, json),
+        Command::Verify {
+            day,
+            answers,
+            record,
+        } => verify(day, answers, record),
+        Command::Generate { day, seed, scale } => generate(day, seed, scale),
+        Command::Visualize {
+            day,
+            input,
+            delay,
+            paused,
+        } => visualize(day, input, delay, paused),
+        Command::Draw { day, input, output } => draw(day, input, output),
+    }
+}
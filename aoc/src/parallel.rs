@@ -0,0 +1,166 @@
+use common::Puzzle;
+use rayon::prelude::*;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+/// Outcome of one phase of a day, parsing or solving a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseResult {
+    /// `parse`, `1` or `2`.
+    pub phase: String,
+    /// The answer, empty for parsing, or what went wrong.
+    pub outcome: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+/// Outcomes of the phases of one day, up to the first one that failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayResult {
+    pub day: u8,
+    pub phases: Vec<PhaseResult>,
+}
+
+impl DayResult {
+    /// Checks whether any phase failed.
+    pub fn failed(&self) -> bool {
+        self.phases.iter().any(|p| p.outcome.is_err())
+    }
+}
+
+/// Runs `f`, turning a panic into an error with its message.
+fn isolate<T, F: FnOnce() -> T>(f: F) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = match payload.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => match payload.downcast_ref::<String>() {
+                Some(message) => message.clone(),
+                None => "unknown cause".to_owned(),
+            },
+        };
+        format!("panicked: {}", message)
+    })
+}
+
+/// Parses `text` and solves `parts` of `puzzle` on it, timing each phase.
+fn run_day(puzzle: &dyn Puzzle, text: &str, parts: &[u8]) -> DayResult {
+    let mut phases = Vec::new();
+    let start = Instant::now();
+    let parsed = isolate(|| puzzle.parse(text))
+        .and_then(|parsed| parsed.map_err(|err| format!("failed to parse input: {}", err)));
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            phases.push(PhaseResult {
+                phase: "parse".to_owned(),
+                outcome: Err(err),
+                elapsed: start.elapsed(),
+            });
+            return DayResult {
+                day: puzzle.day(),
+                phases,
+            };
+        }
+    };
+    phases.push(PhaseResult {
+        phase: "parse".to_owned(),
+        outcome: Ok(String::new()),
+        elapsed: start.elapsed(),
+    });
+
+    for &part in parts {
+        let start = Instant::now();
+        let outcome = isolate(|| parsed.part(part).expect("part is either 1 or 2"));
+        phases.push(PhaseResult {
+            phase: part.to_string(),
+            outcome,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    return DayResult {
+        day: puzzle.day(),
+        phases,
+    };
+}
+
+/// Runs the given days concurrently on a thread pool, each on its input text, and returns
+/// their results ordered by day. Panics are caught so they only fail the phase they occur
+/// in. The panic hook is left unchanged, so panics are still printed unless it is replaced.
+pub fn run_all(days: &[(&'static dyn Puzzle, String)], parts: &[u8]) -> Vec<DayResult> {
+    days.par_iter()
+        .map(|&(puzzle, ref text)| run_day(puzzle, text, parts))
+        .collect()
+}
+
+/// Prints the results as a table with one row per day and phase, continuing multi-line
+/// answers on the following rows.
+pub fn print_table(results: &[DayResult]) {
+    println!(
+        "{:<4} {:<6} {:<40} {:>12}",
+        "day", "part", "answer", "elapsed"
+    );
+    for result in results {
+        for phase in &result.phases {
+            let text = match &phase.outcome {
+                Ok(answer) => answer.clone(),
+                Err(err) => format!("error: {}", err),
+            };
+            let mut lines = text.lines();
+            println!(
+                "{:<4} {:<6} {:<40} {:>12}",
+                format!("{:02}", result.day),
+                phase.phase,
+                lines.next().unwrap_or(""),
+                format!("{:.1?}", phase.elapsed),
+            );
+            for line in lines {
+                println!("{:<4} {:<6} {}", "", "", line);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_run_all() {
+    use common::{puzzle, ParseError, Solution};
+
+    struct Faulty;
+
+    impl Solution for Faulty {
+        const DAY: u8 = 1;
+
+        type Input = u64;
+        type Part1 = u64;
+        type Part2 = u64;
+
+        fn parse(text: &str) -> Result<u64, ParseError> {
+            common::parse_number(text, text.trim())
+        }
+
+        fn part1(input: &u64) -> u64 {
+            input * 2
+        }
+
+        fn part2(_: &u64) -> u64 {
+            panic!("part 2 is broken")
+        }
+    }
+
+    let days = [
+        (puzzle::<Faulty>(), "21".to_owned()),
+        (puzzle::<Faulty>(), "x".to_owned()),
+    ];
+    let results = run_all(&days, &[1, 2]);
+
+    let outcomes: Vec<_> = results[0].phases.iter().map(|p| &p.outcome).collect();
+    assert_eq!(
+        outcomes,
+        [
+            &Ok(String::new()),
+            &Ok("42".to_owned()),
+            &Err("panicked: part 2 is broken".to_owned())
+        ]
+    );
+    assert!(results[1].failed());
+    assert_eq!(results[1].phases.len(), 1);
+}
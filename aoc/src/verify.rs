@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Expected answers of a single day, parts without an answer are not verified.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl DayAnswers {
+    /// Returns the expected answer of `part`.
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Sets the expected answer of `part`.
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            2 => self.part2 = Some(answer),
+            _ => panic!("invalid part {}", part),
+        }
+    }
+}
+
+/// Expected answers by day, keyed `dayNN`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+impl Answers {
+    /// Returns the expected answers of `day`, if any.
+    pub fn day(&self, day: u8) -> Option<&DayAnswers> {
+        self.0.get(&Answers::key(day))
+    }
+
+    /// Returns the expected answers of `day` for modification.
+    pub fn day_mut(&mut self, day: u8) -> &mut DayAnswers {
+        self.0.entry(Answers::key(day)).or_default()
+    }
+
+    fn key(day: u8) -> String {
+        format!("day{:02}", day)
+    }
+
+    /// Reads the answers from a JSON file, an absent file holding no answers.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|e| e.to_string()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+
+    /// Writes the answers to a JSON file.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).expect("answers are serializable");
+        std::fs::write(path, json + "\n").map_err(|e| e.to_string())
+    }
+}
+
+/// Returns the default location of the expected answers, next to the default inputs.
+pub fn default_path() -> PathBuf {
+    common::input::input_dir().join("answers.json")
+}
+
+/// Result of comparing an answer against the expected one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Mismatch { expected: String, actual: String },
+    Unknown { actual: String },
+}
+
+impl Outcome {
+    /// Compares `actual` against `expected`.
+    pub fn check(expected: Option<&str>, actual: String) -> Outcome {
+        match expected {
+            Some(expected) if expected == actual => Outcome::Pass,
+            Some(expected) => Outcome::Mismatch {
+                expected: expected.to_owned(),
+                actual,
+            },
+            None => Outcome::Unknown { actual },
+        }
+    }
+}
+
+/// Renders a line by line diff, marking expected lines with `-` and actual ones with `+`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+    let mut diff = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => diff.push_str(&format!("  {}\n", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    diff.push_str(&format!("- {}\n", e));
+                }
+                if let Some(a) = a {
+                    diff.push_str(&format!("+ {}\n", a));
+                }
+            }
+        }
+    }
+    diff
+}
+
+#[test]
+fn test_check() {
+    assert_eq!(Outcome::check(Some("42"), "42".to_owned()), Outcome::Pass);
+    assert_eq!(
+        Outcome::check(None, "42".to_owned()),
+        Outcome::Unknown {
+            actual: "42".to_owned()
+        }
+    );
+    assert_eq!(diff("a\nb", "a\nc\nd"), "  a\n- b\n+ c\n+ d\n");
+}
+
+#[test]
+fn test_answers_json() {
+    let mut answers = Answers::default();
+    answers.day_mut(1).set(2, "1645".to_owned());
+    let json = serde_json::to_string(&answers).unwrap();
+    assert_eq!(json, r#"{"day01":{"part2":"1645"}}"#);
+    assert_eq!(serde_json::from_str::<Answers>(&json).unwrap(), answers);
+}
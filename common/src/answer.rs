@@ -0,0 +1,61 @@
+use serde_json::{json, Map};
+use std::fmt::Display;
+
+pub use serde_json::Value;
+
+/// Answer of a puzzle part, printed via [`Display`] and reported as JSON together with the
+/// intermediate values it was computed from.
+pub trait Answer: Display {
+    /// The answer as JSON value, a number for numeric answers.
+    fn to_json(&self) -> Value;
+
+    /// Intermediate values the answer was computed from, by name.
+    fn details(&self) -> Vec<(&'static str, Value)> {
+        Vec::new()
+    }
+}
+
+macro_rules! impl_answer {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn to_json(&self) -> Value {
+                    Value::from(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer!(i32, i64, u32, u64, usize);
+
+impl Answer for String {
+    fn to_json(&self) -> Value {
+        Value::from(self.as_str())
+    }
+}
+
+/// Returns the JSON document describing the answer of `part` of `day`.
+pub fn to_json(day: u8, part: u8, answer: &dyn Answer) -> Value {
+    let details = answer
+        .details()
+        .into_iter()
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect::<Map<_, _>>();
+
+    json!({
+        "day": day,
+        "part": part,
+        "answer": answer.to_json(),
+        "details": details,
+    })
+}
+
+#[test]
+fn test_to_json() {
+    assert_eq!(
+        to_json(1, 2, &1645).to_string(),
+        r#"{"answer":1645,"day":1,"details":{},"part":2}"#
+    );
+    assert_eq!(to_json(13, 2, &"#.\n.#".to_owned())["answer"], "#.\n.#");
+}
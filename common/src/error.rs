@@ -0,0 +1,156 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Error describing which part of a puzzle input could not be parsed and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the offending text, starting at 1.
+    pub line: usize,
+    /// Column of the offending text within its line, starting at 1.
+    pub column: usize,
+    /// The offending text, empty if the input ended unexpectedly.
+    pub text: String,
+    /// What is wrong with the text.
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error about `part`, which should be a slice of `source`. Line and column
+    /// are those of `part` within `source`, or of the end of `source` if `part` is not a
+    /// slice of it.
+    pub fn at(source: &str, part: &str, message: &str) -> ParseError {
+        let start = source.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset + part.len() <= source.len())
+            .unwrap_or(source.len());
+        let before = &source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        ParseError {
+            line,
+            column: source[line_start..offset].chars().count() + 1,
+            text: part.to_owned(),
+            message: message.to_owned(),
+        }
+    }
+
+    /// Creates an error about `source` ending before something it should contain.
+    pub fn at_end(source: &str, message: &str) -> ParseError {
+        ParseError::at(source, &source[source.len()..], message)
+    }
+
+    /// Moves the error down by `lines`, for errors found in a part of the input starting
+    /// on a later line.
+    pub fn below(mut self, lines: usize) -> ParseError {
+        self.line += lines;
+        self
+    }
+
+    /// Moves the error, found while parsing `part`, to its position within `source`, of
+    /// which `part` should be a slice.
+    pub fn within(mut self, source: &str, part: &str) -> ParseError {
+        let start = ParseError::at(source, part, "");
+        if self.line == 1 {
+            self.column += start.column - 1;
+        }
+        self.line += start.line - 1;
+        self
+    }
+
+    /// Renders the error together with the offending line of `source`, the input the line
+    /// and column refer to.
+    pub fn diagnostic(&self, source: &str) -> String {
+        match source.lines().nth(self.line - 1) {
+            Some(line) => self.diagnostic_for_line(line),
+            None => self.to_string(),
+        }
+    }
+
+    /// Renders the error together with `line`, the offending line, for inputs that are not
+    /// kept in memory as a whole.
+    pub fn diagnostic_for_line(&self, line: &str) -> String {
+        let number = self.line.to_string();
+        let padding = " ".repeat(number.len());
+        let width = self.text.lines().next().unwrap_or("").chars().count();
+        let marker = "^".repeat(width.max(1));
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}{}",
+            self,
+            padding,
+            number,
+            line,
+            padding,
+            " ".repeat(self.column - 1),
+            marker
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses every line of `text` with `f`, correcting the line numbers of its errors.
+pub fn parse_lines<'a, T, F>(text: &'a str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&'a str) -> Result<T, ParseError>,
+{
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.below(i)))
+        .collect()
+}
+
+/// Parses `part`, a slice of `source`, as a number.
+pub fn parse_number<T: FromStr>(source: &str, part: &str) -> Result<T, ParseError> {
+    part.parse()
+        .map_err(|_| ParseError::at(source, part, "invalid number"))
+}
+
+#[test]
+fn test_position() {
+    let source = "1,2\n3,x4\n";
+    let err = parse_number::<i32>(source, &source[6..8]).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(err.to_string(), "line 2, column 3: invalid number `x4`");
+    assert_eq!(
+        err.diagnostic(source),
+        "line 2, column 3: invalid number `x4`\n  |\n2 | 3,x4\n  |   ^^"
+    );
+
+    let err = ParseError::at_end(source, "expected another line");
+    assert_eq!((err.line, err.column), (3, 1));
+}
+
+#[test]
+fn test_within() {
+    let source = "a\nb: 1,x";
+    let part = &source[5..];
+    let err = parse_number::<i32>(part, &part[2..]).unwrap_err();
+    assert_eq!((err.line, err.column), (1, 3));
+
+    let err = err.within(source, part);
+    assert_eq!((err.line, err.column), (2, 6));
+}
+
+#[test]
+fn test_parse_lines() {
+    assert_eq!(parse_lines("1\n2", |l| parse_number(l, l)), Ok(vec![1, 2]));
+
+    let err = parse_lines("1\n2\n-", |l| parse_number::<u8>(l, l)).unwrap_err();
+    assert_eq!((err.line, err.column), (3, 1));
+}
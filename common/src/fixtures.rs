@@ -0,0 +1,75 @@
+use crate::Solution;
+use std::path::{Path, PathBuf};
+
+/// Example input of a puzzle together with the answers it is expected to give.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub name: String,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Returns the directory containing the examples of `day`. An example `NAME` consists of
+/// its input `NAME.txt` and the expected answers `NAME.part1` and `NAME.part2`, which may
+/// be missing for parts the puzzle gives no answer for.
+pub fn fixture_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../fixtures/day{:02}", day))
+}
+
+fn read_answer(path: &Path) -> Option<String> {
+    let answer = std::fs::read_to_string(path).ok()?;
+    Some(answer.trim_end_matches('\n').to_owned())
+}
+
+/// Returns the examples of `day`, ordered by name.
+pub fn fixtures(day: u8) -> Vec<Fixture> {
+    let dir = fixture_dir(day);
+    let entries = std::fs::read_dir(&dir)
+        .unwrap_or_else(|err| panic!("failed to read {}: {}", dir.display(), err));
+
+    let mut fixtures: Vec<Fixture> = entries
+        .map(|entry| entry.expect("directory entry is readable").path())
+        .filter(|path| path.extension().is_some_and(|e| e == "txt"))
+        .map(|path| Fixture {
+            name: path.file_stem().unwrap().to_string_lossy().into_owned(),
+            input: std::fs::read_to_string(&path).expect("example is readable"),
+            part1: read_answer(&path.with_extension("part1")),
+            part2: read_answer(&path.with_extension("part2")),
+        })
+        .collect();
+    fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+    fixtures
+}
+
+/// Returns the input of the example `name` of `day`.
+pub fn fixture(day: u8, name: &str) -> String {
+    let path = fixture_dir(day).join(format!("{}.txt", name));
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {}", path.display(), err))
+}
+
+/// Solves every example of solution `S` and checks the answers against the expected ones,
+/// panicking on the first mismatch.
+pub fn check<S: Solution>() {
+    let fixtures = fixtures(S::DAY);
+    assert!(!fixtures.is_empty(), "day {} has no examples", S::DAY);
+
+    for fixture in fixtures {
+        let parsed = S::parse(&fixture.input).unwrap_or_else(|err| {
+            panic!(
+                "failed to parse example {}: {}",
+                fixture.name,
+                err.diagnostic(&fixture.input)
+            )
+        });
+        if let Some(expected) = &fixture.part1 {
+            let actual = S::part1(&parsed).to_string();
+            assert_eq!(&actual, expected, "part 1 of example {}", fixture.name);
+        }
+        if let Some(expected) = &fixture.part2 {
+            let actual = S::part2(&parsed).to_string();
+            assert_eq!(&actual, expected, "part 2 of example {}", fixture.name);
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::env;
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the input of a single day, either a path or `-` for stdin.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Flags of the day binaries taking a value, given as `--name=value` or `--name value`.
+pub const VALUE_FLAGS: [&str; 6] = ["csv", "part", "run", "svg", "trace", "window"];
+
+/// Environment variable naming a directory containing `dayNN.txt` files to use instead of
+/// the `inputs` directory of this repository.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Source a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// A file on disk.
+    Path(PathBuf),
+    /// Everything that can be read from standard input.
+    Stdin,
+    /// Text that is already in memory, e.g. a generated input.
+    Text(String),
+}
+
+impl Input {
+    /// Interprets a command line argument, `-` meaning stdin and anything else a path.
+    pub fn parse(arg: &str) -> Input {
+        if arg == "-" {
+            Input::Stdin
+        } else {
+            Input::Path(PathBuf::from(arg))
+        }
+    }
+
+    /// Returns the default input file of `day`, located in `$AOC_INPUT_DIR` if set or in
+    /// the `inputs` directory of this repository otherwise.
+    pub fn default_for(day: u8) -> Input {
+        Input::Path(input_dir().join(format!("day{:02}.txt", day)))
+    }
+
+    /// Returns the input given by `$AOC_INPUT`, falling back to [`Input::default_for`].
+    pub fn from_env(day: u8) -> Input {
+        match env::var(INPUT_VAR) {
+            Ok(value) if !value.is_empty() => Input::parse(&value),
+            _ => Input::default_for(day),
+        }
+    }
+
+    /// Returns the input given as first command line argument that is neither a `--` flag
+    /// nor the value of one, falling back to [`Input::from_env`].
+    pub fn from_args(day: u8) -> Input {
+        let args: Vec<String> = env::args().skip(1).collect();
+        match input_arg(&args) {
+            Some(arg) => Input::parse(arg),
+            None => Input::from_env(day),
+        }
+    }
+
+    /// Reads the whole input into a string.
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Input::Path(path) => std::fs::read_to_string(path),
+            Input::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Ok(text)
+            }
+            Input::Text(text) => Ok(text.clone()),
+        }
+    }
+}
+
+/// Returns the value of the command line flag `--name=value` or `--name value`, if given.
+pub fn flag_value(name: &str) -> Option<String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    find_flag_value(&args, name)
+}
+
+/// Returns the first of `args` that is neither a flag nor the value of one of
+/// [`VALUE_FLAGS`].
+fn input_arg(args: &[String]) -> Option<&str> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.strip_prefix("--") {
+            Some(flag) if VALUE_FLAGS.contains(&flag) => {
+                args.next();
+            }
+            Some(_) => {}
+            None => return Some(arg),
+        }
+    }
+    return None;
+}
+
+/// Returns the value of the flag `name` in `args`, see [`flag_value`].
+fn find_flag_value(args: &[String], name: &str) -> Option<String> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let Some(flag) = arg.strip_prefix("--") else {
+            continue;
+        };
+        if let Some(value) = flag
+            .strip_prefix(name)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            return Some(value.to_owned());
+        }
+        if flag == name {
+            return args.next().cloned();
+        }
+        // The value of another flag is not a flag itself, even if it starts with `--`
+        if VALUE_FLAGS.contains(&flag) {
+            args.next();
+        }
+    }
+    return None;
+}
+
+/// Returns the directory containing the default inputs, `$AOC_INPUT_DIR` if set or the
+/// `inputs` directory of this repository otherwise.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs"))
+}
+
+impl From<&str> for Input {
+    fn from(text: &str) -> Self {
+        Input::Text(text.to_owned())
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Input::Path(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::Text(_) => write!(f, "<text>"),
+        }
+    }
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(Input::parse("-"), Input::Stdin);
+    assert_eq!(
+        Input::parse("inputs/day01.txt"),
+        Input::Path(PathBuf::from("inputs/day01.txt"))
+    );
+}
+
+#[test]
+fn test_read_text() {
+    let input = Input::from("199\n200\n");
+    assert_eq!(input.read().unwrap(), "199\n200\n");
+}
+
+#[test]
+fn test_args() {
+    let args: Vec<String> = ["--profile", "--window", "5", "--csv=out.csv", "day01.txt"]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+    assert_eq!(input_arg(&args), Some("day01.txt"));
+    assert_eq!(find_flag_value(&args, "window").as_deref(), Some("5"));
+    assert_eq!(find_flag_value(&args, "csv").as_deref(), Some("out.csv"));
+    assert_eq!(find_flag_value(&args, "part"), None);
+    assert_eq!(input_arg(&args[..3]), None);
+}
@@ -0,0 +1,17 @@
+//! Functionality shared by the solutions of all days.
+
+pub mod answer;
+pub mod error;
+pub mod fixtures;
+pub mod input;
+pub mod parser;
+pub mod search;
+pub mod solution;
+pub mod svg;
+pub mod visualize;
+
+pub use answer::Answer;
+pub use error::{parse_lines, parse_number, ParseError};
+pub use input::{flag_value, Input};
+pub use parser::{sections, Parser};
+pub use solution::{puzzle, run, Parsed, Puzzle, Solution};
@@ -0,0 +1,177 @@
+use crate::error::{parse_number, ParseError};
+use std::str::FromStr;
+
+/// Parser consuming a text from the front, with errors positioned within that text.
+///
+/// Formats are declared by chaining the pieces they consist of, e.g. a line like
+/// `on x=-5..47` is read with `one_of(&["on", "off"])`, `tag(" x=")` and `range()`.
+#[derive(Debug, Clone)]
+pub struct Parser<'a> {
+    source: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    /// Creates a parser at the start of `source`.
+    pub fn new(source: &'a str) -> Parser<'a> {
+        Parser {
+            source,
+            rest: source,
+        }
+    }
+
+    /// Returns the text that has not been consumed yet.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    /// Creates an error about the text at the current position.
+    pub fn error(&self, message: &str) -> ParseError {
+        match self.rest.lines().next() {
+            Some(line) if !line.is_empty() => ParseError::at(self.source, line, message),
+            _ => ParseError::at(self.source, &self.rest[..0], message),
+        }
+    }
+
+    /// Consumes `expected`.
+    pub fn tag(&mut self, expected: &str) -> Result<(), ParseError> {
+        match self.rest.strip_prefix(expected) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => Err(self.error(&format!("expected `{}`", expected))),
+        }
+    }
+
+    /// Consumes whichever of `alternatives` comes next and returns it.
+    pub fn one_of(&mut self, alternatives: &[&'a str]) -> Result<&'a str, ParseError> {
+        for &alternative in alternatives {
+            if self.tag(alternative).is_ok() {
+                return Ok(alternative);
+            }
+        }
+
+        let alternatives: Vec<_> = alternatives.iter().map(|a| format!("`{}`", a)).collect();
+        Err(self.error(&format!("expected one of {}", alternatives.join(", "))))
+    }
+
+    /// Consumes the longest prefix whose characters all satisfy `predicate`.
+    pub fn take_while<P: Fn(char) -> bool>(&mut self, predicate: P) -> &'a str {
+        let end = self
+            .rest
+            .char_indices()
+            .find(|&(_, c)| !predicate(c))
+            .map_or(self.rest.len(), |(i, _)| i);
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    /// Consumes a single character satisfying `predicate`, `what` describing it for errors.
+    pub fn char_where<P: Fn(char) -> bool>(
+        &mut self,
+        predicate: P,
+        what: &str,
+    ) -> Result<char, ParseError> {
+        match self.rest.chars().next() {
+            Some(c) if predicate(c) => {
+                self.rest = &self.rest[c.len_utf8()..];
+                Ok(c)
+            }
+            _ => Err(self.error(&format!("expected {}", what))),
+        }
+    }
+
+    /// Skips spaces and tabs.
+    pub fn whitespace(&mut self) {
+        self.take_while(|c| c == ' ' || c == '\t');
+    }
+
+    /// Consumes an integer, with a leading `-` if it is negative.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let sign = if self.rest.starts_with('-') { 1 } else { 0 };
+        let digits = self.rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len() - sign);
+        if digits == 0 {
+            return Err(self.error("expected a number"));
+        }
+
+        let (number, rest) = self.rest.split_at(sign + digits);
+        self.rest = rest;
+        parse_number(self.source, number)
+    }
+
+    /// Consumes two items separated by `separator`, like the `a` and `b` of `a -> b`.
+    pub fn separated<A, B, FA, FB>(
+        &mut self,
+        separator: &str,
+        mut first: FA,
+        mut second: FB,
+    ) -> Result<(A, B), ParseError>
+    where
+        FA: FnMut(&mut Parser<'a>) -> Result<A, ParseError>,
+        FB: FnMut(&mut Parser<'a>) -> Result<B, ParseError>,
+    {
+        let a = first(self)?;
+        self.tag(separator)?;
+        let b = second(self)?;
+        Ok((a, b))
+    }
+
+    /// Consumes an inclusive range of integers like `-5..47`.
+    pub fn range<T: FromStr>(&mut self) -> Result<(T, T), ParseError> {
+        self.separated("..", Parser::number, Parser::number)
+    }
+
+    /// Consumes one or more items separated by `separator`, like a comma separated list.
+    pub fn list<T, F>(&mut self, separator: &str, mut item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&mut Parser<'a>) -> Result<T, ParseError>,
+    {
+        let mut items = vec![item(self)?];
+        while self.tag(separator).is_ok() {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Checks that everything has been consumed.
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("unexpected text"))
+        }
+    }
+}
+
+/// Splits `text` into its sections, which are separated by blank lines.
+pub fn sections(text: &str) -> impl Iterator<Item = &str> {
+    text.trim_end()
+        .split("\n\n")
+        .filter(|section| !section.is_empty())
+}
+
+#[test]
+fn test_parser() {
+    let mut parser = Parser::new("on x=-5..47,y=3..3");
+    assert_eq!(parser.one_of(&["on", "off"]), Ok("on"));
+    parser.tag(" x=").unwrap();
+    assert_eq!(parser.range::<i32>(), Ok((-5, 47)));
+    parser.tag(",y=").unwrap();
+    assert_eq!(parser.range::<i32>(), Ok((3, 3)));
+    assert!(parser.end().is_ok());
+
+    let mut parser = Parser::new("1,2 -> 3,x");
+    let point = |p: &mut Parser| p.list(",", Parser::number::<u8>);
+    let err = parser.separated(" -> ", point, point).unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 10: expected a number `x`");
+}
+
+#[test]
+fn test_sections() {
+    let text = "1,2\n\na\nb\n\nc\n";
+    assert_eq!(sections(text).collect::<Vec<_>>(), vec!["1,2", "a\nb", "c"]);
+}
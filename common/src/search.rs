@@ -0,0 +1,55 @@
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns the lowest total cost of getting from `start` to a state satisfying `is_goal`,
+/// where `successors` returns the states a state leads to together with the cost of each
+/// move.
+pub fn lowest_cost<S, F, G>(start: S, mut successors: F, mut is_goal: G) -> Option<u64>
+where
+    S: Hash + Eq,
+    F: FnMut(&S) -> Vec<(S, u64)>,
+    G: FnMut(&S) -> bool,
+{
+    // Dijkstra's algorithm, with states in the open list until their cost is final
+    let mut open_list = PriorityQueue::new();
+    let mut closed_list = HashSet::new();
+
+    open_list.push(start, Reverse(0));
+
+    while let Some((state, Reverse(cost))) = open_list.pop() {
+        if is_goal(&state) {
+            return Some(cost);
+        }
+
+        for (next, move_cost) in successors(&state) {
+            if closed_list.contains(&next) {
+                continue;
+            }
+            let new_cost = cost + move_cost;
+            match open_list.get_priority(&next) {
+                Some(&Reverse(old_cost)) if old_cost <= new_cost => {}
+                Some(_) => {
+                    open_list.change_priority(&next, Reverse(new_cost));
+                }
+                None => {
+                    open_list.push(next, Reverse(new_cost));
+                }
+            }
+        }
+
+        closed_list.insert(state);
+    }
+
+    return None;
+}
+
+#[test]
+fn test_lowest_cost() {
+    // Going up by one costs 3, doubling costs 5
+    let successors = |&n: &u32| vec![(n + 1, 3), (n * 2, 5)];
+    assert_eq!(lowest_cost(1, successors, |&n| n == 8), Some(13));
+    assert_eq!(lowest_cost(1, successors, |&n| n == 7), Some(14));
+    assert_eq!(lowest_cost(1, |_| vec![], |&n: &u32| n == 2), None);
+}
@@ -0,0 +1,139 @@
+use crate::{answer, Answer, Input, ParseError};
+use std::marker::PhantomData;
+
+/// Solution of a single day: parses the puzzle input into a day-specific model and solves
+/// both parts of the puzzle on it.
+pub trait Solution {
+    /// Day of December the puzzle was published on.
+    const DAY: u8;
+
+    /// Model the puzzle input is parsed into.
+    type Input;
+    /// Answer of part 1.
+    type Part1: Answer + 'static;
+    /// Answer of part 2.
+    type Part2: Answer + 'static;
+
+    /// Parses the puzzle input.
+    fn parse(text: &str) -> Result<Self::Input, ParseError>;
+    /// Solves part 1 of the puzzle.
+    fn part1(input: &Self::Input) -> Self::Part1;
+    /// Solves part 2 of the puzzle.
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// Object safe view of a [`Solution`], so tooling can treat all days uniformly.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+    fn parse(&self, text: &str) -> Result<Box<dyn Parsed>, ParseError>;
+}
+
+/// Puzzle input parsed by a [`Puzzle`], ready to be solved.
+pub trait Parsed {
+    fn part1(&self) -> Box<dyn Answer>;
+    fn part2(&self) -> Box<dyn Answer>;
+
+    /// Solves part 1 or 2, returns `None` for any other part.
+    fn answer(&self, part: u8) -> Option<Box<dyn Answer>> {
+        match part {
+            1 => Some(self.part1()),
+            2 => Some(self.part2()),
+            _ => None,
+        }
+    }
+
+    /// Solves part 1 or 2 and formats the answer, returns `None` for any other part.
+    fn part(&self, part: u8) -> Option<String> {
+        self.answer(part).map(|answer| answer.to_string())
+    }
+}
+
+/// Returns the [`Puzzle`] of solution `S`.
+pub fn puzzle<S>() -> &'static dyn Puzzle
+where
+    S: Solution + Sync + 'static,
+{
+    &PuzzleOf::<S>(PhantomData)
+}
+
+struct PuzzleOf<S>(PhantomData<S>);
+
+impl<S> Puzzle for PuzzleOf<S>
+where
+    S: Solution + Sync + 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, text: &str) -> Result<Box<dyn Parsed>, ParseError> {
+        Ok(Box::new(ParsedOf::<S>(S::parse(text)?)))
+    }
+}
+
+struct ParsedOf<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for ParsedOf<S> {
+    fn part1(&self) -> Box<dyn Answer> {
+        Box::new(S::part1(&self.0))
+    }
+
+    fn part2(&self) -> Box<dyn Answer> {
+        Box::new(S::part2(&self.0))
+    }
+}
+
+/// Prints an answer after the given label, starting multi-line answers on a new line.
+pub fn print_answer(label: &str, answer: &str) {
+    if answer.contains('\n') {
+        println!("{}:\n{}", label, answer);
+    } else {
+        println!("{}: {}", label, answer);
+    }
+}
+
+/// Reports that `input` is malformed, `diagnostic` showing where, and exits.
+pub fn exit_on_parse_error(input: &Input, diagnostic: &str) -> ! {
+    eprintln!("error: failed to parse {}: {}", input, diagnostic);
+    std::process::exit(1);
+}
+
+/// Reads the input of `day` given on the command line and parses it with `parse`,
+/// exiting with a diagnostic if it cannot be read or is malformed.
+pub fn load<T, F>(day: u8, parse: F) -> T
+where
+    F: FnOnce(&str) -> Result<T, ParseError>,
+{
+    let input = Input::from_args(day);
+    let text = match input.read() {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("error: failed to read {}: {}", input, err);
+            std::process::exit(1);
+        }
+    };
+
+    match parse(&text) {
+        Ok(parsed) => parsed,
+        Err(err) => exit_on_parse_error(&input, &err.diagnostic(&text)),
+    }
+}
+
+/// Runs solution `S` on the input given on the command line and prints both answers, as
+/// JSON if `--json` is given.
+pub fn run<S: Solution>() {
+    let parsed = load(S::DAY, S::parse);
+    let part1 = S::part1(&parsed);
+    let part2 = S::part2(&parsed);
+    if std::env::args().any(|arg| arg == "--json") {
+        let answers = vec![
+            answer::to_json(S::DAY, 1, &part1),
+            answer::to_json(S::DAY, 2, &part2),
+        ];
+        let json = serde_json::to_string_pretty(&answers).expect("answers are serializable");
+        println!("{}", json);
+    } else {
+        print_answer("part 1", &part1.to_string());
+        print_answer("part 2", &part2.to_string());
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// Length in pixels of the longer side of a picture.
+const SIZE: f64 = 800.0;
+
+/// Colour scale of [`heat`], from cold to hot.
+const HEAT: [(f64, (u8, u8, u8)); 5] = [
+    (0.0, (49, 54, 149)),
+    (0.25, (116, 173, 209)),
+    (0.5, (255, 255, 191)),
+    (0.75, (244, 109, 67)),
+    (1.0, (165, 0, 38)),
+];
+
+/// Returns the colour of `t` between 0 (cold, blue) and 1 (hot, red) as `#rrggbb`.
+pub fn heat(t: f64) -> String {
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    let i = HEAT.iter().rposition(|&(start, _)| start <= t).unwrap_or(0);
+    let (start, (r1, g1, b1)) = HEAT[i];
+    let (end, (r2, g2, b2)) = HEAT[(i + 1).min(HEAT.len() - 1)];
+    let f = if end > start {
+        (t - start) / (end - start)
+    } else {
+        0.0
+    };
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * f).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", mix(r1, r2), mix(g1, g2), mix(b1, b2))
+}
+
+/// Fill and outline of a shape. Outlines are as wide in pixels regardless of the scale
+/// of the picture.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    fill: Option<String>,
+    stroke: Option<(String, f64)>,
+    opacity: f64,
+    dashed: bool,
+}
+
+impl Style {
+    /// Creates a style filling shapes with `color` without outlining them.
+    pub fn fill(color: &str) -> Style {
+        Style {
+            fill: Some(color.to_owned()),
+            stroke: None,
+            opacity: 1.0,
+            dashed: false,
+        }
+    }
+
+    /// Creates a style outlining shapes with `color`, `width` pixels wide, without filling
+    /// them.
+    pub fn stroke(color: &str, width: f64) -> Style {
+        Style {
+            fill: None,
+            stroke: Some((color.to_owned(), width)),
+            opacity: 1.0,
+            dashed: false,
+        }
+    }
+
+    /// Adds an outline to the style.
+    pub fn with_stroke(mut self, color: &str, width: f64) -> Style {
+        self.stroke = Some((color.to_owned(), width));
+        self
+    }
+
+    /// Makes shapes translucent, 0 being invisible and 1 opaque.
+    pub fn with_opacity(mut self, opacity: f64) -> Style {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Draws the outline dashed.
+    pub fn dashed(mut self) -> Style {
+        self.dashed = true;
+        self
+    }
+
+    fn attributes(&self) -> String {
+        let mut attributes = format!(" fill=\"{}\"", self.fill.as_deref().unwrap_or("none"));
+        if let Some((color, width)) = &self.stroke {
+            attributes.push_str(&format!(
+                " stroke=\"{}\" stroke-width=\"{}\" vector-effect=\"non-scaling-stroke\"",
+                color, width
+            ));
+            if self.dashed {
+                attributes.push_str(&format!(" stroke-dasharray=\"{}\"", width * 4.0));
+            }
+        }
+        if self.opacity < 1.0 {
+            attributes.push_str(&format!(" opacity=\"{}\"", self.opacity));
+        }
+        attributes
+    }
+}
+
+/// Rounds `value` to three decimals, which is precise enough for a picture and keeps
+/// the file short.
+fn round(value: f64) -> f64 {
+    (value * 1000.0).round() / 1000.0
+}
+
+/// Escapes the characters of `text` that have a meaning in XML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Picture of shapes in the coordinates of a puzzle, with `y` growing downwards, scaled
+/// so its longer side is 800 pixels.
+#[derive(Debug, Clone)]
+pub struct Svg {
+    view: (f64, f64, f64, f64),
+    elements: Vec<String>,
+}
+
+impl Svg {
+    /// Creates a picture showing the region from `(x, y)` to `(x + width, y + height)`
+    /// on a white background.
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Svg {
+        let mut svg = Svg {
+            view: (x, y, width.max(1.0), height.max(1.0)),
+            elements: Vec::new(),
+        };
+        let (x, y, width, height) = svg.view;
+        svg.rect(x, y, width, height, &Style::fill("white"));
+        svg
+    }
+
+    /// Returns the length of a pixel in the coordinates of the picture.
+    pub fn pixel(&self) -> f64 {
+        let (_, _, width, height) = self.view;
+        width.max(height) / SIZE
+    }
+
+    /// Draws a rectangle with its top left corner at `(x, y)`.
+    pub fn rect(&mut self, x: f64, y: f64, width: f64, height: f64, style: &Style) {
+        self.elements.push(format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"{}/>",
+            round(x),
+            round(y),
+            round(width),
+            round(height),
+            style.attributes()
+        ));
+    }
+
+    /// Fills the unit square at `(x, y)`, like a cell of a grid.
+    pub fn cell(&mut self, x: f64, y: f64, color: &str) {
+        self.rect(x, y, 1.0, 1.0, &Style::fill(color));
+    }
+
+    /// Draws a line from `(x1, y1)` to `(x2, y2)`.
+    pub fn line(&mut self, (x1, y1): (f64, f64), (x2, y2): (f64, f64), style: &Style) {
+        self.elements.push(format!(
+            "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"{}/>",
+            round(x1),
+            round(y1),
+            round(x2),
+            round(y2),
+            style.attributes()
+        ));
+    }
+
+    /// Draws a line through all `points`.
+    pub fn polyline(&mut self, points: &[(f64, f64)], style: &Style) {
+        let points: Vec<_> = points
+            .iter()
+            .map(|&(x, y)| format!("{},{}", round(x), round(y)))
+            .collect();
+        self.elements.push(format!(
+            "<polyline points=\"{}\"{}/>",
+            points.join(" "),
+            style.attributes()
+        ));
+    }
+
+    /// Marks the point `(x, y)` with a dot `radius` pixels wide.
+    pub fn point(&mut self, x: f64, y: f64, radius: f64, color: &str) {
+        self.elements.push(format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\"{}/>",
+            round(x),
+            round(y),
+            round(radius * self.pixel()),
+            Style::fill(color).attributes()
+        ));
+    }
+
+    /// Writes `label` with its baseline starting at `(x, y)`, 14 pixels high.
+    pub fn text(&mut self, x: f64, y: f64, label: &str, color: &str) {
+        self.elements.push(format!(
+            "<text x=\"{}\" y=\"{}\" font-family=\"monospace\" font-size=\"{}\" fill=\"{}\">{}</text>",
+            round(x),
+            round(y),
+            round(14.0 * self.pixel()),
+            color,
+            escape(label)
+        ));
+    }
+
+    /// Writes the picture to the file at `path`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+}
+
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (x, y, width, height) = self.view;
+        let scale = SIZE / width.max(height);
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"{} {} {} {}\">",
+            (width * scale).round(),
+            (height * scale).round(),
+            round(x),
+            round(y),
+            round(width),
+            round(height)
+        )?;
+        for element in &self.elements {
+            writeln!(f, "{}", element)?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
+
+#[test]
+fn test_svg() {
+    let mut svg = Svg::new(0.0, 0.0, 8.0, 4.0);
+    svg.cell(1.0, 2.0, &heat(1.0));
+    svg.polyline(
+        &[(0.0, 0.0), (1.5, 2.0)],
+        &Style::stroke("black", 2.0).dashed(),
+    );
+    svg.text(0.0, 4.0, "a < b", "black");
+
+    let text = svg.to_string();
+    let lines: Vec<_> = text.lines().collect();
+    assert_eq!(
+        lines[0],
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"800\" height=\"400\" viewBox=\"0 0 8 4\">"
+    );
+    assert_eq!(
+        lines[2],
+        "<rect x=\"1\" y=\"2\" width=\"1\" height=\"1\" fill=\"#a50026\"/>"
+    );
+    assert_eq!(
+        lines[3],
+        "<polyline points=\"0,0 1.5,2\" fill=\"none\" stroke=\"black\" stroke-width=\"2\" \
+         vector-effect=\"non-scaling-stroke\" stroke-dasharray=\"8\"/>"
+    );
+    assert!(lines[4].ends_with(">a &lt; b</text>"));
+    assert_eq!(lines[5], "</svg>");
+    assert_eq!(heat(0.0), "#313695");
+    assert_eq!(heat(0.5), "#ffffbf");
+}
@@ -0,0 +1,71 @@
+/// Colour of a character of a [`Frame`], drawn with the terminal's palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Default,
+    Dim,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+/// Rendered state of a simulation, a status line above rows of coloured characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub status: String,
+    pub rows: Vec<Vec<(char, Color)>>,
+}
+
+impl Frame {
+    /// Creates a `width` by `height` frame of blanks.
+    pub fn new(status: String, width: usize, height: usize) -> Frame {
+        Frame {
+            status,
+            rows: vec![vec![(' ', Color::Default); width]; height],
+        }
+    }
+
+    /// Sets the character at `(x, y)`, ignoring positions outside of the frame.
+    pub fn set(&mut self, x: usize, y: usize, c: char, color: Color) {
+        if let Some(cell) = self.rows.get_mut(y).and_then(|row| row.get_mut(x)) {
+            *cell = (c, color);
+        }
+    }
+
+    /// Appends a row showing `text` in `color`.
+    pub fn push_text(&mut self, text: &str, color: Color) {
+        self.rows.push(text.chars().map(|c| (c, color)).collect());
+    }
+
+    /// Returns the characters without their colours, one line per row.
+    pub fn to_plain_text(&self) -> String {
+        let mut text = String::new();
+        for row in &self.rows {
+            text.extend(row.iter().map(|&(c, _)| c));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Simulation that can be animated one step at a time, to watch how its state evolves.
+pub trait Simulation {
+    /// Advances the simulation by one step, returns `false` if it had already finished.
+    fn step(&mut self) -> bool;
+
+    /// Renders the current state.
+    fn render(&self) -> Frame;
+}
+
+#[test]
+fn test_frame() {
+    let mut frame = Frame::new("step 0".to_owned(), 3, 2);
+    frame.set(1, 0, '#', Color::Yellow);
+    frame.set(5, 5, '#', Color::Yellow);
+    frame.push_text("ok", Color::Green);
+    assert_eq!(frame.to_plain_text(), " # \n   \nok\n");
+    assert_eq!(frame.rows[0][1], ('#', Color::Yellow));
+}
@@ -0,0 +1,15 @@
+//! Random sonar sweeps.
+
+use rand::Rng;
+
+/// Generates `2000 * scale` depth measurements that mostly increase, like a sweep away
+/// from the shore.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let mut depth: i32 = rng.gen_range(100..200);
+    let mut text = String::new();
+    for _ in 0..2000 * scale {
+        text.push_str(&format!("{}\n", depth));
+        depth = (depth + rng.gen_range(-10..=20)).max(0);
+    }
+    return text;
+}
@@ -0,0 +1,109 @@
+//! Day 1: Sonar Sweep
+
+use common::{parse_lines, parse_number, Input, ParseError, Solution};
+use std::error::Error;
+
+pub mod generator;
+pub mod profile;
+pub mod stream;
+pub mod window;
+
+use window::{SlidingWindows, Window};
+
+/// Counts how often the sonar depth measurements increase.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(text: &str) -> Result<Vec<i32>, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(depths: &Vec<i32>) -> i32 {
+        count_increases(depths, 1)
+    }
+
+    fn part2(depths: &Vec<i32>) -> i32 {
+        count_increases(depths, 3)
+    }
+}
+
+/// Reads the depth measurements from `input`.
+pub fn get_input(input: &Input) -> Result<Vec<i32>, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses one depth measurement per line.
+pub fn parse_input(text: &str) -> Result<Vec<i32>, ParseError> {
+    parse_lines(text, |line| parse_number(line, line))
+}
+
+/// Counts how often the sum of a sliding window of `size` measurements is larger than
+/// the sum of the previous window. A size of 1 compares single measurements.
+pub fn count_increases(depths: &[i32], size: usize) -> i32 {
+    let mut previous: Option<i32> = None;
+    let mut increased_count = 0;
+    for sum in depths.iter().copied().window_sums(size) {
+        if previous.is_some_and(|previous| previous < sum) {
+            increased_count += 1;
+        }
+        previous = Some(sum);
+    }
+
+    return increased_count;
+}
+
+/// Counter of window increases for measurements that are pushed one at a time.
+#[derive(Debug, Clone)]
+pub struct IncreaseCounter {
+    window: Window<i32>,
+    previous: Option<i32>,
+    count: i32,
+}
+
+impl IncreaseCounter {
+    /// Creates a counter comparing sliding windows of `size` measurements.
+    pub fn new(size: usize) -> Self {
+        IncreaseCounter {
+            window: Window::new(size),
+            previous: None,
+            count: 0,
+        }
+    }
+
+    /// Adds the next measurement and returns whether it completed a window with a larger
+    /// sum than the previous one.
+    pub fn push(&mut self, depth: i32) -> bool {
+        let Some(sum) = self.window.push(depth) else {
+            return false;
+        };
+        let increased = self.previous.is_some_and(|previous| previous < sum);
+        if increased {
+            self.count += 1;
+        }
+        self.previous = Some(sum);
+        return increased;
+    }
+
+    /// Returns the number of increases so far.
+    pub fn count(&self) -> i32 {
+        self.count
+    }
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day01>();
+}
+
+#[test]
+fn test_count_increases() {
+    let depths = parse_input(&common::fixtures::fixture(1, "example")).unwrap();
+    let counts = [1, 3, 5, 10].map(|size| count_increases(&depths, size));
+    assert_eq!(counts, [7, 5, 5, 0]);
+}
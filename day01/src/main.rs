@@ -1,43 +1,77 @@
+use common::solution::{exit_on_parse_error, load, print_answer};
+use common::{flag_value, Input};
+use day01::profile::profile;
+use day01::stream::{stream, Progress, StreamError};
 use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
+use std::io::{self, BufRead, BufReader};
 
-fn main() {
-    let file = File::open("../inputs/day01.txt").expect("input not found");
-    let lines = BufReader::new(file).lines();
-
-    // Part 1
-    let mut prev_depth: Option<i32> = None;
-    let mut increased_count = 0;
-
-    // Part 2
-    let mut prev_depths: [i32; 3] = [0; 3];
-    let mut increased_count_sw = 0;
+/// Counts increases while the measurements arrive, printing the running counts after each
+/// one. With `--follow`, an input file is watched for appended lines instead of stopping
+/// at its end.
+fn run_stream(follow: bool) {
+    let input = Input::from_args(1);
+    let reader: Box<dyn BufRead> = match &input {
+        Input::Path(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(err) => {
+                eprintln!("error: failed to read {}: {}", input, err);
+                std::process::exit(1);
+            }
+        },
+        Input::Stdin => Box::new(io::stdin().lock()),
+        Input::Text(text) => Box::new(io::Cursor::new(text.clone().into_bytes())),
+    };
+    let follow = follow && matches!(input, Input::Path(_));
 
-    for (i, line) in lines.enumerate() {
-        let depth: i32 = line
-            .expect("failed to read line")
-            .parse()
-            .expect("failed to parse line");
+    let report = |p: &Progress| {
+        println!(
+            "measurement {} ({}): part 1 {}, part 2 {}",
+            p.measurements, p.depth, p.part1, p.part2
+        )
+    };
+    match stream(reader, follow, report) {
+        Ok(progress) => {
+            print_answer("part 1", &progress.part1.to_string());
+            print_answer("part 2", &progress.part2.to_string());
+        }
+        Err(err @ StreamError::Parse(..)) => exit_on_parse_error(&input, &err.diagnostic()),
+        Err(StreamError::Io(err)) => {
+            eprintln!("error: failed to read {}: {}", input, err);
+            std::process::exit(1);
+        }
+    }
+}
 
-        // Part 1
-        match prev_depth {
-            Some(d) if d < depth => increased_count += 1,
-            _ => (),
+/// Prints the depth profile of the measurements with a trend of windows of
+/// `--window=N` measurements, 3 by default, and writes the trend to `--csv=PATH` if
+/// given.
+fn run_profile() {
+    let window = match flag_value("window").map(|value| value.parse::<usize>()) {
+        None => 3,
+        Some(Ok(window)) if window > 0 => window,
+        Some(_) => {
+            eprintln!("error: --window must be a positive number");
+            std::process::exit(1);
         }
-        prev_depth = Some(depth);
+    };
+    let depths = load(1, day01::parse_input);
 
-        // Part 2
-        if i >= 3 {
-            let prev_sw: i32 = prev_depths.iter().sum();
-            let next_sw = prev_depths[(i - 2) % 3] + prev_depths[(i - 1) % 3] + depth;
-            if next_sw > prev_sw {
-                increased_count_sw += 1;
-            }
+    let profile = profile(&depths, window);
+    println!("{}", profile);
+    if let Some(path) = flag_value("csv") {
+        if let Err(err) = std::fs::write(&path, profile.trend_csv()) {
+            eprintln!("error: failed to write {}: {}", path, err);
+            std::process::exit(1);
         }
-        prev_depths[i % 3] = depth
     }
+}
 
-    println!("part 1: depth increased {} times", increased_count);
-    println!("part 2: depth increased {} times", increased_count_sw);
+fn main() {
+    if std::env::args().any(|arg| arg == "--stream") {
+        run_stream(std::env::args().any(|arg| arg == "--follow"));
+    } else if std::env::args().any(|arg| arg == "--profile") {
+        run_profile();
+    } else {
+        common::run::<day01::Day01>();
+    }
 }
@@ -0,0 +1,213 @@
+//! Depth profile of a sonar sweep, beyond counting increases.
+
+use crate::window::SlidingWindows;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Measurement with its index in the sweep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    pub index: usize,
+    pub depth: i32,
+}
+
+/// Consecutive measurements that each are deeper, or each shallower, than the one before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Run {
+    /// Index of the measurement the run starts from.
+    pub start: usize,
+    /// Number of changes in the run, one less than the measurements it spans.
+    pub length: usize,
+}
+
+/// Sum of a sliding window and how it changed from the previous window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrendPoint {
+    /// Index of the first measurement of the window.
+    pub index: usize,
+    pub sum: i32,
+    /// Difference to the sum of the previous window, missing for the first window.
+    pub change: Option<i32>,
+}
+
+/// Summary of the measurements of a sonar sweep.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub measurements: usize,
+    pub shallowest: Option<Measurement>,
+    pub deepest: Option<Measurement>,
+    pub longest_increase: Run,
+    pub longest_decrease: Run,
+    /// Measurement that is the most shallower than the one before.
+    pub largest_drop: Option<(Measurement, i32)>,
+    /// Window size of the trend.
+    pub window: usize,
+    pub trend: Vec<TrendPoint>,
+}
+
+/// Analyses `depths`, computing the trend of sliding windows of `window` measurements.
+/// Panics if `window` is 0.
+pub fn profile(depths: &[i32], window: usize) -> Profile {
+    let measurements = || {
+        depths
+            .iter()
+            .enumerate()
+            .map(|(index, &depth)| Measurement { index, depth })
+    };
+    // The first of equally shallow or deep measurements
+    let shallowest = measurements().min_by_key(|m| (m.depth, m.index));
+    let deepest = measurements().max_by_key(|m| (m.depth, usize::MAX - m.index));
+
+    let mut longest_increase = Run::default();
+    let mut longest_decrease = Run::default();
+    let mut current = (Ordering::Equal, Run::default());
+    let mut largest_drop: Option<(Measurement, i32)> = None;
+    for (i, pair) in depths.windows(2).enumerate() {
+        let direction = pair[1].cmp(&pair[0]);
+        if direction == current.0 {
+            current.1.length += 1;
+        } else {
+            current = (
+                direction,
+                Run {
+                    start: i,
+                    length: 1,
+                },
+            );
+        }
+        match direction {
+            Ordering::Greater if current.1.length > longest_increase.length => {
+                longest_increase = current.1
+            }
+            Ordering::Less if current.1.length > longest_decrease.length => {
+                longest_decrease = current.1
+            }
+            _ => (),
+        }
+
+        let drop = pair[0] - pair[1];
+        if drop > 0 && largest_drop.is_none_or(|(_, largest)| drop > largest) {
+            let measurement = Measurement {
+                index: i + 1,
+                depth: pair[1],
+            };
+            largest_drop = Some((measurement, drop));
+        }
+    }
+
+    let mut trend: Vec<TrendPoint> = Vec::new();
+    for (index, sum) in depths.iter().copied().window_sums(window).enumerate() {
+        let change = trend.last().map(|previous| sum - previous.sum);
+        trend.push(TrendPoint { index, sum, change });
+    }
+
+    return Profile {
+        measurements: depths.len(),
+        shallowest,
+        deepest,
+        longest_increase,
+        longest_decrease,
+        largest_drop,
+        window,
+        trend,
+    };
+}
+
+impl Profile {
+    /// Returns the trend as CSV with a header line, the direction of each change being
+    /// `up`, `down` or `flat`.
+    pub fn trend_csv(&self) -> String {
+        let mut csv = String::from("index,sum,change,direction\n");
+        for point in &self.trend {
+            let (change, direction) = match point.change {
+                Some(change) => {
+                    let direction = match change.cmp(&0) {
+                        Ordering::Greater => "up",
+                        Ordering::Less => "down",
+                        Ordering::Equal => "flat",
+                    };
+                    (change.to_string(), direction)
+                }
+                None => (String::new(), ""),
+            };
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                point.index, point.sum, change, direction
+            ));
+        }
+        csv
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let measurement = |m: Option<Measurement>| match m {
+            Some(m) => format!("{} at index {}", m.depth, m.index),
+            None => "-".to_owned(),
+        };
+        let run = |run: Run| match run.length {
+            0 => "-".to_owned(),
+            length => format!("{} steps from index {}", length, run.start),
+        };
+
+        writeln!(f, "measurements:     {}", self.measurements)?;
+        writeln!(f, "shallowest:       {}", measurement(self.shallowest))?;
+        writeln!(f, "deepest:          {}", measurement(self.deepest))?;
+        writeln!(f, "longest increase: {}", run(self.longest_increase))?;
+        writeln!(f, "longest decrease: {}", run(self.longest_decrease))?;
+        match self.largest_drop {
+            Some((m, drop)) => writeln!(
+                f,
+                "largest drop:     {} down to {} at index {}",
+                drop, m.depth, m.index
+            )?,
+            None => writeln!(f, "largest drop:     -")?,
+        }
+        let (up, down) = self
+            .trend
+            .iter()
+            .fold((0, 0), |(up, down), p| match p.change {
+                Some(c) if c > 0 => (up + 1, down),
+                Some(c) if c < 0 => (up, down + 1),
+                _ => (up, down),
+            });
+        write!(
+            f,
+            "trend:            {} windows of {}, {} increases, {} decreases",
+            self.trend.len(),
+            self.window,
+            up,
+            down
+        )
+    }
+}
+
+#[test]
+fn test_profile() {
+    let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+    let profile = profile(&depths, 3);
+    let at = |m: Option<Measurement>| m.map(|m| (m.index, m.depth));
+    assert_eq!(at(profile.shallowest), Some((0, 199)));
+    assert_eq!(at(profile.deepest), Some((7, 269)));
+    assert_eq!(
+        profile.longest_increase,
+        Run {
+            start: 0,
+            length: 3
+        }
+    );
+    assert_eq!(
+        profile.longest_decrease,
+        Run {
+            start: 3,
+            length: 1
+        }
+    );
+    let (drop_at, drop) = profile.largest_drop.unwrap();
+    assert_eq!((drop_at.index, drop), (4, 10));
+    assert_eq!(profile.trend.len(), 8);
+    assert_eq!(
+        profile.trend_csv().lines().take(3).collect::<Vec<_>>(),
+        ["index,sum,change,direction", "0,607,,", "1,618,11,up"]
+    );
+}
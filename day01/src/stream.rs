@@ -0,0 +1,126 @@
+//! Counting increases of measurements while they are still arriving.
+
+use crate::IncreaseCounter;
+use common::{parse_number, ParseError};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::thread;
+use std::time::Duration;
+
+/// Time to wait for more measurements when following a growing input.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Running answers after some of the measurements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Progress {
+    /// Number of measurements read so far.
+    pub measurements: usize,
+    /// Depth of the last measurement.
+    pub depth: i32,
+    /// Increases of single measurements so far.
+    pub part1: i32,
+    /// Increases of three-measurement windows so far.
+    pub part2: i32,
+}
+
+/// Reason streaming the measurements stopped.
+#[derive(Debug)]
+pub enum StreamError {
+    /// The input could not be read.
+    Io(io::Error),
+    /// A line is not a measurement. The line is kept, as the input is not.
+    Parse(ParseError, String),
+}
+
+impl StreamError {
+    /// Renders the error, showing the offending line of a parse error.
+    pub fn diagnostic(&self) -> String {
+        match self {
+            StreamError::Io(err) => err.to_string(),
+            StreamError::Parse(err, line) => err.diagnostic_for_line(line),
+        }
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "{}", err),
+            StreamError::Parse(err, _) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+/// Reads one measurement per line from `reader`, calling `report` after each one with the
+/// running counts, and returns the final counts. Blank lines are skipped. Only a line at
+/// a time is kept in memory. With `follow` set, the end of the input is waited out for
+/// more lines to be appended, like `tail -f`, so this only returns on an error.
+pub fn stream<R, F>(mut reader: R, follow: bool, mut report: F) -> Result<Progress, StreamError>
+where
+    R: BufRead,
+    F: FnMut(&Progress),
+{
+    let (mut part1, mut part2) = (IncreaseCounter::new(1), IncreaseCounter::new(3));
+    let mut progress = Progress::default();
+    let mut line = String::new();
+    let mut line_number = 0;
+    loop {
+        let read = reader.read_line(&mut line)?;
+        if read == 0 || !line.ends_with('\n') {
+            // Wait for the rest of a line that is still being written
+            if follow {
+                thread::sleep(POLL_INTERVAL);
+                continue;
+            }
+            if line.is_empty() {
+                return Ok(progress);
+            }
+        }
+
+        let text = line.trim();
+        if !text.is_empty() {
+            progress.depth = match parse_number(text, text) {
+                Ok(depth) => depth,
+                Err(err) => {
+                    let err = err.within(&line, text).below(line_number);
+                    let line = line.trim_end_matches(['\n', '\r']).to_owned();
+                    return Err(StreamError::Parse(err, line));
+                }
+            };
+            progress.measurements += 1;
+            part1.push(progress.depth);
+            part2.push(progress.depth);
+            progress.part1 = part1.count();
+            progress.part2 = part2.count();
+            report(&progress);
+        }
+        line_number += 1;
+        line.clear();
+    }
+}
+
+#[test]
+fn test_stream() {
+    let text = "199\n200\n\n208\n210\r\n200\n207\n240\n269\n260\n263";
+    let mut reports = Vec::new();
+    let progress = stream(text.as_bytes(), false, |p| reports.push((p.part1, p.part2))).unwrap();
+    assert_eq!(
+        (progress.measurements, progress.part1, progress.part2),
+        (10, 7, 5)
+    );
+    assert_eq!(reports[..4], [(0, 0), (1, 0), (2, 0), (3, 1)]);
+
+    let err = stream("1\n\n  x\n".as_bytes(), false, |_| ()).unwrap_err();
+    let expected = common::ParseError::at("  x", &"  x"[2..], "invalid number").below(2);
+    assert_eq!(err.to_string(), expected.to_string());
+    assert_eq!(err.diagnostic(), expected.diagnostic("1\n\n  x\n"));
+}
@@ -0,0 +1,94 @@
+//! Sums of sliding windows over a sequence of numbers.
+
+use std::collections::VecDeque;
+use std::ops::{Add, Sub};
+
+/// Running sum of the last `size` items pushed into it.
+#[derive(Debug, Clone)]
+pub struct Window<T> {
+    size: usize,
+    items: VecDeque<T>,
+    sum: T,
+}
+
+impl<T> Window<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Creates an empty window of `size` items. Panics if `size` is 0.
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "window size must be at least 1");
+        Window {
+            size,
+            items: VecDeque::with_capacity(size + 1),
+            sum: T::default(),
+        }
+    }
+
+    /// Adds `item`, dropping the oldest item once the window is full, and returns the sum
+    /// of the window if it is full.
+    pub fn push(&mut self, item: T) -> Option<T> {
+        self.items.push_back(item);
+        self.sum = self.sum + item;
+        if self.items.len() > self.size {
+            let oldest = self.items.pop_front().expect("window is not empty");
+            self.sum = self.sum - oldest;
+        }
+
+        if self.items.len() == self.size {
+            Some(self.sum)
+        } else {
+            None
+        }
+    }
+}
+
+/// Iterator over the sums of all windows of `size` consecutive items of another iterator,
+/// keeping a running sum instead of adding up every window.
+pub struct WindowSums<I: Iterator> {
+    iter: I,
+    window: Window<I::Item>,
+}
+
+impl<I, T> Iterator for WindowSums<I>
+where
+    I: Iterator<Item = T>,
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            let item = self.iter.next()?;
+            if let Some(sum) = self.window.push(item) {
+                return Some(sum);
+            }
+        }
+    }
+}
+
+/// Adds [`window_sums`](SlidingWindows::window_sums) to iterators over numbers.
+pub trait SlidingWindows: Iterator + Sized {
+    /// Returns the sums of all windows of `size` consecutive items, of which there are
+    /// none if there are fewer than `size` items. Panics if `size` is 0.
+    fn window_sums(self, size: usize) -> WindowSums<Self>
+    where
+        Self::Item: Copy + Default + Add<Output = Self::Item> + Sub<Output = Self::Item>,
+    {
+        WindowSums {
+            iter: self,
+            window: Window::new(size),
+        }
+    }
+}
+
+impl<I: Iterator> SlidingWindows for I {}
+
+#[test]
+fn test_window_sums() {
+    let depths = [199, 200, 208, 210, 200];
+    let sums: Vec<i32> = depths.iter().copied().window_sums(3).collect();
+    assert_eq!(sums, [607, 618, 618]);
+    assert_eq!(depths.iter().copied().window_sums(1).count(), 5);
+    assert_eq!(depths.iter().copied().window_sums(6).count(), 0);
+}
@@ -0,0 +1,26 @@
+//! Random planned courses.
+
+use rand::Rng;
+
+/// Generates `1000 * scale` steps. The submarine never aims above the surface, so `up`
+/// only ever reduces the aim down to zero.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let mut aim = 0;
+    let mut text = String::new();
+    for _ in 0..1000 * scale {
+        let value = rng.gen_range(1..10);
+        let direction = match rng.gen_range(0..3) {
+            0 => "forward",
+            1 if aim >= value => {
+                aim -= value;
+                "up"
+            }
+            _ => {
+                aim += value;
+                "down"
+            }
+        };
+        text.push_str(&format!("{} {}\n", direction, value));
+    }
+    return text;
+}
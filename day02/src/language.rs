@@ -0,0 +1,154 @@
+//! Language of planned courses, one command per line.
+//!
+//! ```text
+//! # dive a bit, three times
+//! repeat 3 {
+//!     forward 5
+//!     down 2   # steeper
+//! }
+//! back 4
+//! reset-aim
+//! ```
+//!
+//! Besides `forward`, `up` and `down` of the puzzle, `back` moves backwards and
+//! `reset-aim` levels the submarine. `repeat N { ... }` runs its block `N` times and
+//! blocks may be nested. Everything after a `#` is a comment.
+
+use common::{ParseError, Parser};
+
+/// Direction of a single step of the planned course.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Forward,
+    Back,
+}
+
+/// Command of a planned course.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Moves in a direction by a value.
+    Move(Direction, i32),
+    /// Sets the aim back to zero.
+    ResetAim,
+    /// Runs the commands a number of times.
+    Repeat(usize, Vec<Command>),
+}
+
+/// Block of a `repeat` whose `}` has not been read yet.
+struct OpenBlock<'a> {
+    count: usize,
+    commands: Vec<Command>,
+    line: &'a str,
+    line_index: usize,
+}
+
+/// Parses a planned course, reporting errors by their position in `text`.
+pub fn parse_course(text: &str) -> Result<Vec<Command>, ParseError> {
+    let mut blocks: Vec<OpenBlock> = Vec::new();
+    let mut commands = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let code = line.split('#').next().unwrap_or("").trim();
+        if code.is_empty() {
+            continue;
+        }
+        let error = |e: ParseError| e.within(line, code).below(i);
+
+        let mut parser = Parser::new(code);
+        let command = match parser.take_while(|c| c != ' ') {
+            "forward" => {
+                Command::Move(Direction::Forward, parse_value(&mut parser).map_err(error)?)
+            }
+            "up" => Command::Move(Direction::Up, parse_value(&mut parser).map_err(error)?),
+            "down" => Command::Move(Direction::Down, parse_value(&mut parser).map_err(error)?),
+            "back" => Command::Move(Direction::Back, parse_value(&mut parser).map_err(error)?),
+            "reset-aim" => {
+                parser.end().map_err(error)?;
+                Command::ResetAim
+            }
+            "repeat" => {
+                let count = parse_repeat(&mut parser).map_err(error)?;
+                blocks.push(OpenBlock {
+                    count,
+                    commands: std::mem::take(&mut commands),
+                    line,
+                    line_index: i,
+                });
+                continue;
+            }
+            "}" => {
+                parser.end().map_err(error)?;
+                let Some(block) = blocks.pop() else {
+                    return Err(error(ParseError::at(code, code, "no block to close")));
+                };
+                let body = std::mem::replace(&mut commands, block.commands);
+                Command::Repeat(block.count, body)
+            }
+            _ => return Err(error(ParseError::at(code, code, "unknown command"))),
+        };
+        commands.push(command);
+    }
+
+    if let Some(block) = blocks.pop() {
+        let err = ParseError::at(block.line, block.line.trim(), "block is not closed");
+        return Err(err.below(block.line_index));
+    }
+    return Ok(commands);
+}
+
+/// Parses the ` N` after the name of a move, `N` being unsigned.
+fn parse_value(parser: &mut Parser) -> Result<i32, ParseError> {
+    parser.tag(" ")?;
+    if !parser.rest().starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(parser.error("expected an unsigned number"));
+    }
+    let value = parser.number()?;
+    parser.end()?;
+    return Ok(value);
+}
+
+/// Parses the ` N {` after `repeat`.
+fn parse_repeat(parser: &mut Parser) -> Result<usize, ParseError> {
+    parser.tag(" ")?;
+    let count = parser.number()?;
+    parser.whitespace();
+    parser.tag("{")?;
+    parser.end()?;
+    return Ok(count);
+}
+
+#[test]
+fn test_parse_course() {
+    let text = "# warm up\nforward 1\nrepeat 2 {\n  down 3 # dive\n  repeat 1 {\n    back 2\n  }\n}\nreset-aim\n";
+    assert_eq!(
+        parse_course(text).unwrap(),
+        [
+            Command::Move(Direction::Forward, 1),
+            Command::Repeat(
+                2,
+                vec![
+                    Command::Move(Direction::Down, 3),
+                    Command::Repeat(1, vec![Command::Move(Direction::Back, 2)]),
+                ]
+            ),
+            Command::ResetAim,
+        ]
+    );
+
+    let err = parse_course("forward 1\nrepeat 2 {\n  up x\n}").unwrap_err();
+    assert_eq!((err.line, err.column), (3, 6));
+    let err = parse_course("forward 1\n repeat 2 {\n  up 1\n").unwrap_err();
+    assert_eq!(
+        (err.line, err.column, err.message.as_str()),
+        (2, 2, "block is not closed")
+    );
+    let err = parse_course("forward 1\ndown -3").unwrap_err();
+    assert_eq!(
+        (err.line, err.column, err.message.as_str()),
+        (2, 6, "expected an unsigned number")
+    );
+    let err = parse_course("}").unwrap_err();
+    assert_eq!(err.message, "no block to close");
+}
@@ -0,0 +1,144 @@
+//! Day 2: Dive!
+
+use common::answer::{Answer, Value};
+use common::{Input, ParseError, Solution};
+use std::error::Error;
+use std::fmt;
+
+pub mod generator;
+pub mod language;
+pub mod semantics;
+pub mod trajectory;
+
+pub use language::{Command, Direction};
+use semantics::{interpret, Aimed, Direct, OutOfRange};
+
+/// Follows the planned course of the submarine.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Command>;
+    type Part1 = Position;
+    type Part2 = Position;
+
+    fn parse(text: &str) -> Result<Vec<Command>, ParseError> {
+        let program = parse_input(text)?;
+        if run_part_1(&program).is_err() || run_part_2(&program).is_err() {
+            let message = "course takes the submarine out of range";
+            return Err(ParseError::at_end(text, message));
+        }
+        Ok(program)
+    }
+
+    fn part1(program: &Vec<Command>) -> Position {
+        run_part_1(program).expect("course is checked when parsing")
+    }
+
+    fn part2(program: &Vec<Command>) -> Position {
+        run_part_2(program).expect("course is checked when parsing")
+    }
+}
+
+/// Position the submarine ends up in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    pub depth: i32,
+    pub horizontal: i32,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.depth as i64 * self.horizontal as i64)
+    }
+}
+
+impl Answer for Position {
+    fn to_json(&self) -> Value {
+        Value::from(self.depth as i64 * self.horizontal as i64)
+    }
+
+    fn details(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("horizontal", Value::from(self.horizontal)),
+            ("depth", Value::from(self.depth)),
+        ]
+    }
+}
+
+/// Reads the planned course from `input`.
+pub fn get_input(input: &Input) -> Result<Vec<Command>, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses the planned course, see [`language`] for the commands.
+pub fn parse_input(text: &str) -> Result<Vec<Command>, ParseError> {
+    language::parse_course(text)
+}
+
+/// Moves the submarine with `up` and `down` changing the depth directly.
+pub fn run_part_1(program: &[Command]) -> Result<Position, OutOfRange> {
+    interpret::<Direct>(program)
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day02>();
+}
+
+#[test]
+fn test_part_1() {
+    let position = run_part_1(&[
+        Command::Move(Direction::Forward, 5),
+        Command::Move(Direction::Down, 5),
+        Command::Move(Direction::Forward, 8),
+        Command::Move(Direction::Up, 3),
+        Command::Move(Direction::Down, 8),
+        Command::Move(Direction::Forward, 2),
+    ])
+    .unwrap();
+    assert_eq!(
+        position,
+        Position {
+            depth: 10,
+            horizontal: 15,
+        }
+    );
+}
+
+/// Moves the submarine with `up` and `down` changing the aim, which determines how much
+/// `forward` changes the depth.
+pub fn run_part_2(program: &[Command]) -> Result<Position, OutOfRange> {
+    interpret::<Aimed>(program)
+}
+
+#[test]
+fn test_part_2() {
+    let position = run_part_2(&[
+        Command::Move(Direction::Forward, 5),
+        Command::Move(Direction::Down, 5),
+        Command::Move(Direction::Forward, 8),
+        Command::Move(Direction::Up, 3),
+        Command::Move(Direction::Down, 8),
+        Command::Move(Direction::Forward, 2),
+    ])
+    .unwrap();
+    assert_eq!(
+        position,
+        Position {
+            depth: 60,
+            horizontal: 15,
+        }
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    let err = parse_input("forward 5\ndown 5\nbackward 3\n").unwrap_err();
+    assert_eq!((err.line, err.column), (3, 1));
+    assert_eq!(err.text, "backward 3");
+
+    let err = Day02::parse("down 50000\nrepeat 50000 {\n  forward 1\n}\n").unwrap_err();
+    assert_eq!(err.message, "course takes the submarine out of range");
+}
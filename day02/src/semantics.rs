@@ -0,0 +1,169 @@
+//! Interpretations of the commands of a planned course.
+
+use crate::language::{Command, Direction};
+use crate::Position;
+use std::fmt;
+
+/// Error of a course taking the submarine further than its state can represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRange;
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "position, depth or aim out of range")
+    }
+}
+
+impl std::error::Error for OutOfRange {}
+
+fn add(a: i32, b: i32) -> Result<i32, OutOfRange> {
+    a.checked_add(b).ok_or(OutOfRange)
+}
+
+fn sub(a: i32, b: i32) -> Result<i32, OutOfRange> {
+    a.checked_sub(b).ok_or(OutOfRange)
+}
+
+fn mul(a: i32, b: i32) -> Result<i32, OutOfRange> {
+    a.checked_mul(b).ok_or(OutOfRange)
+}
+
+/// Meaning of the commands, as state of the submarine that they change.
+pub trait Semantics {
+    /// Moves the submarine in `direction` by `value`, failing if it leaves the range of
+    /// its state.
+    fn step(&mut self, direction: Direction, value: i32) -> Result<(), OutOfRange>;
+
+    /// Sets the aim back to zero.
+    fn reset_aim(&mut self);
+
+    /// Returns where the submarine currently is.
+    fn position(&self) -> Position;
+
+    /// Returns the current aim, if the semantics have one.
+    fn aim(&self) -> Option<i32> {
+        None
+    }
+}
+
+/// Runs `program` on the state of `semantics`, stopping at the first move out of range.
+pub fn execute<S: Semantics + ?Sized>(
+    semantics: &mut S,
+    program: &[Command],
+) -> Result<(), OutOfRange> {
+    for command in program {
+        match command {
+            &Command::Move(direction, value) => semantics.step(direction, value)?,
+            Command::ResetAim => semantics.reset_aim(),
+            Command::Repeat(count, body) => {
+                for _ in 0..*count {
+                    execute(semantics, body)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Runs `program` from the starting position and returns the final position.
+pub fn interpret<S: Semantics + Default>(program: &[Command]) -> Result<Position, OutOfRange> {
+    let mut semantics = S::default();
+    execute(&mut semantics, program)?;
+    return Ok(semantics.position());
+}
+
+/// Semantics of part 1, `up` and `down` change the depth directly.
+#[derive(Debug, Clone, Default)]
+pub struct Direct {
+    pub depth: i32,
+    pub horizontal: i32,
+}
+
+impl Semantics for Direct {
+    fn step(&mut self, direction: Direction, value: i32) -> Result<(), OutOfRange> {
+        match direction {
+            Direction::Up => self.depth = sub(self.depth, value)?,
+            Direction::Down => self.depth = add(self.depth, value)?,
+            Direction::Forward => self.horizontal = add(self.horizontal, value)?,
+            Direction::Back => self.horizontal = sub(self.horizontal, value)?,
+        }
+        Ok(())
+    }
+
+    /// There is no aim, the submarine is always level.
+    fn reset_aim(&mut self) {}
+
+    fn position(&self) -> Position {
+        Position {
+            depth: self.depth,
+            horizontal: self.horizontal,
+        }
+    }
+}
+
+/// Semantics of part 2, `up` and `down` change the aim, which determines how much moving
+/// `forward` or `back` changes the depth.
+#[derive(Debug, Clone, Default)]
+pub struct Aimed {
+    pub aim: i32,
+    pub depth: i32,
+    pub horizontal: i32,
+}
+
+impl Semantics for Aimed {
+    fn step(&mut self, direction: Direction, value: i32) -> Result<(), OutOfRange> {
+        match direction {
+            Direction::Up => self.aim = sub(self.aim, value)?,
+            Direction::Down => self.aim = add(self.aim, value)?,
+            Direction::Forward => {
+                self.horizontal = add(self.horizontal, value)?;
+                self.depth = add(self.depth, mul(self.aim, value)?)?;
+            }
+            Direction::Back => {
+                self.horizontal = sub(self.horizontal, value)?;
+                self.depth = sub(self.depth, mul(self.aim, value)?)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn reset_aim(&mut self) {
+        self.aim = 0;
+    }
+
+    fn position(&self) -> Position {
+        Position {
+            depth: self.depth,
+            horizontal: self.horizontal,
+        }
+    }
+
+    fn aim(&self) -> Option<i32> {
+        Some(self.aim)
+    }
+}
+
+#[test]
+fn test_extended_commands() {
+    let program =
+        crate::parse_input("down 2\nrepeat 3 {\n  forward 4\n}\nback 2\nreset-aim\nforward 1")
+            .unwrap();
+    assert_eq!(
+        interpret::<Direct>(&program).unwrap(),
+        Position {
+            depth: 2,
+            horizontal: 11
+        }
+    );
+    assert_eq!(
+        interpret::<Aimed>(&program).unwrap(),
+        Position {
+            depth: 20,
+            horizontal: 11
+        }
+    );
+
+    let program = crate::parse_input("down 50000\nrepeat 50000 {\n  forward 1\n}").unwrap();
+    assert!(interpret::<Direct>(&program).is_ok());
+    assert_eq!(interpret::<Aimed>(&program), Err(OutOfRange));
+}
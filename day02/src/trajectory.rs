@@ -0,0 +1,168 @@
+//! Recording every position the submarine passes through.
+
+use crate::language::{Command, Direction};
+use crate::semantics::{execute, OutOfRange, Semantics};
+use crate::Position;
+use common::svg::{Style, Svg};
+
+/// State of the submarine after a command.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    pub position: Position,
+    /// Aim of the submarine, if the semantics have one.
+    pub aim: Option<i32>,
+}
+
+/// Positions of the submarine from the start, after every executed command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trajectory {
+    pub samples: Vec<Sample>,
+}
+
+/// Semantics recording the state of other semantics after every command.
+pub struct Recorder<S> {
+    pub semantics: S,
+    pub trajectory: Trajectory,
+}
+
+impl<S: Semantics> Recorder<S> {
+    /// Starts recording at the current state of `semantics`.
+    pub fn new(semantics: S) -> Self {
+        let mut recorder = Recorder {
+            semantics,
+            trajectory: Trajectory {
+                samples: Vec::new(),
+            },
+        };
+        recorder.sample();
+        recorder
+    }
+
+    fn sample(&mut self) {
+        self.trajectory.samples.push(Sample {
+            position: self.semantics.position(),
+            aim: self.semantics.aim(),
+        });
+    }
+}
+
+impl<S: Semantics> Semantics for Recorder<S> {
+    fn step(&mut self, direction: Direction, value: i32) -> Result<(), OutOfRange> {
+        self.semantics.step(direction, value)?;
+        self.sample();
+        Ok(())
+    }
+
+    fn reset_aim(&mut self) {
+        self.semantics.reset_aim();
+        self.sample();
+    }
+
+    fn position(&self) -> Position {
+        self.semantics.position()
+    }
+
+    fn aim(&self) -> Option<i32> {
+        self.semantics.aim()
+    }
+}
+
+/// Runs `program` from the starting position and returns every position on the way.
+pub fn record<S: Semantics + Default>(program: &[Command]) -> Result<Trajectory, OutOfRange> {
+    let mut recorder = Recorder::new(S::default());
+    execute(&mut recorder, program)?;
+    return Ok(recorder.trajectory);
+}
+
+impl Trajectory {
+    /// Returns the samples as CSV with a header line, numbered from 0 for the start. The
+    /// aim is left empty for semantics without one.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,horizontal,depth,aim\n");
+        for (step, sample) in self.samples.iter().enumerate() {
+            let aim = sample.aim.map(|aim| aim.to_string()).unwrap_or_default();
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                step, sample.position.horizontal, sample.position.depth, aim
+            ));
+        }
+        csv
+    }
+
+    /// Plots the depth over the horizontal position, with depth growing downwards. The
+    /// depth is scaled so the plot is half as high as it is wide.
+    pub fn to_svg(&self) -> Svg {
+        let positions: Vec<Position> = self.samples.iter().map(|s| s.position).collect();
+        let (min_x, max_x) = bounds(positions.iter().map(|p| p.horizontal));
+        let (min_depth, max_depth) = bounds(positions.iter().map(|p| p.depth));
+        let width = (max_x as f64 - min_x as f64).max(1.0);
+        let scale = width / 2.0 / (max_depth as f64 - min_depth as f64).max(1.0);
+        let y = |depth: i32| depth as f64 * scale;
+
+        let margin = width / 20.0;
+        let mut svg = Svg::new(
+            min_x as f64 - margin,
+            y(min_depth) - 2.0 * margin,
+            width + 2.0 * margin,
+            width / 2.0 + 3.0 * margin,
+        );
+        svg.line(
+            (min_x as f64 - margin, 0.0),
+            (max_x as f64 + margin, 0.0),
+            &Style::stroke("#74add1", 1.0).dashed(),
+        );
+        let points: Vec<_> = positions
+            .iter()
+            .map(|p| (p.horizontal as f64, y(p.depth)))
+            .collect();
+        svg.polyline(&points, &Style::stroke("#313695", 1.5));
+
+        if let Some(deepest) = positions.iter().max_by_key(|p| p.depth) {
+            svg.point(deepest.horizontal as f64, y(deepest.depth), 4.0, "#d73027");
+        }
+        let end = positions.last().copied().unwrap_or(Position {
+            depth: 0,
+            horizontal: 0,
+        });
+        svg.text(
+            min_x as f64 - margin + 4.0 * svg.pixel(),
+            y(min_depth) - 2.0 * margin + 16.0 * svg.pixel(),
+            &format!(
+                "{} steps, deepest {}, ends at horizontal {} depth {}",
+                positions.len() - 1,
+                max_depth,
+                end.horizontal,
+                end.depth
+            ),
+            "black",
+        );
+        return svg;
+    }
+}
+
+/// Returns the smallest and largest of `values` and 0.
+fn bounds(values: impl Iterator<Item = i32>) -> (i32, i32) {
+    values.fold((0, 0), |(min, max), v| (min.min(v), max.max(v)))
+}
+
+#[test]
+fn test_record() {
+    use crate::semantics::{Aimed, Direct};
+
+    let program = crate::parse_input(&common::fixtures::fixture(2, "example")).unwrap();
+    let trajectory = record::<Aimed>(&program).unwrap();
+    assert_eq!(trajectory.samples.len(), 7);
+    assert_eq!(
+        trajectory.samples.last().unwrap().position,
+        crate::run_part_2(&program).unwrap()
+    );
+    let csv = trajectory.to_csv();
+    assert_eq!(
+        csv.lines().take(4).collect::<Vec<_>>(),
+        ["step,horizontal,depth,aim", "0,0,0,0", "1,5,0,0", "2,5,0,5"]
+    );
+
+    let trajectory = record::<Direct>(&program).unwrap();
+    assert!(trajectory.to_csv().ends_with("6,15,10,\n"));
+    assert!(trajectory.to_svg().to_string().starts_with("<svg "));
+}
@@ -0,0 +1,22 @@
+//! Random diagnostic reports.
+
+use rand::Rng;
+use std::collections::HashSet;
+
+/// Generates a report of `1000 * scale` distinct binary numbers. The numbers are at least
+/// 12 bits wide and wide enough for half of the possible numbers to remain unused, so the
+/// life support ratings always filter down to a single number.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let count = 1000 * scale;
+    let width = (12..32).find(|&w| 1usize << w >= 2 * count).unwrap_or(32);
+
+    let mut seen = HashSet::new();
+    let mut text = String::new();
+    while seen.len() < count {
+        let value = rng.gen_range(0..1u64 << width);
+        if seen.insert(value) {
+            text.push_str(&format!("{:0width$b}\n", value, width = width));
+        }
+    }
+    return text;
+}
@@ -0,0 +1,207 @@
+//! Day 3: Binary Diagnostic
+
+use common::answer::{Answer, Value};
+use common::{parse_lines, Input, ParseError, Solution};
+use std::error::Error;
+use std::fmt;
+
+pub mod generator;
+
+/// Decodes the power consumption and life support rating from the diagnostic report.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<u32>;
+    type Part1 = Result;
+    type Part2 = LifeSupportRating;
+
+    fn parse(text: &str) -> std::result::Result<Vec<u32>, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(report: &Vec<u32>) -> Result {
+        calc_power_consumption(report)
+    }
+
+    fn part2(report: &Vec<u32>) -> LifeSupportRating {
+        let (oxygen_generator_rating, c02_scrubber_rating) = calc_life_support_rating(report);
+        LifeSupportRating {
+            oxygen_generator_rating,
+            c02_scrubber_rating,
+        }
+    }
+}
+
+/// Reads the diagnostic report from `input`.
+pub fn get_input(input: &Input) -> std::result::Result<Vec<u32>, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses one binary number per line.
+pub fn parse_input(text: &str) -> std::result::Result<Vec<u32>, ParseError> {
+    parse_lines(text, |line| {
+        u32::from_str_radix(line, 2)
+            .map_err(|_| ParseError::at(line, line, "invalid binary number"))
+    })
+}
+
+/// Gamma and epsilon rate, whose product is the power consumption.
+#[derive(Debug, PartialEq)]
+pub struct Result {
+    pub epsilon: u32,
+    pub gamma: u32,
+}
+
+impl fmt::Display for Result {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.gamma * self.epsilon)
+    }
+}
+
+impl Answer for Result {
+    fn to_json(&self) -> Value {
+        Value::from(self.gamma * self.epsilon)
+    }
+
+    fn details(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("gamma", Value::from(self.gamma)),
+            ("epsilon", Value::from(self.epsilon)),
+        ]
+    }
+}
+
+/// Oxygen generator and CO2 scrubber rating, whose product is the life support rating.
+#[derive(Debug, PartialEq)]
+pub struct LifeSupportRating {
+    pub oxygen_generator_rating: u32,
+    pub c02_scrubber_rating: u32,
+}
+
+impl fmt::Display for LifeSupportRating {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.oxygen_generator_rating * self.c02_scrubber_rating
+        )
+    }
+}
+
+impl Answer for LifeSupportRating {
+    fn to_json(&self) -> Value {
+        Value::from(self.oxygen_generator_rating * self.c02_scrubber_rating)
+    }
+
+    fn details(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            (
+                "oxygen_generator_rating",
+                Value::from(self.oxygen_generator_rating),
+            ),
+            ("c02_scrubber_rating", Value::from(self.c02_scrubber_rating)),
+        ]
+    }
+}
+
+/// Calculates gamma and epsilon rate from the most and least common bits of the report.
+pub fn calc_power_consumption(report: &[u32]) -> Result {
+    let mut zero_bits = [0; 32];
+    let mut one_bits = [0; 32];
+
+    for value in report {
+        for i in 0..32 {
+            let check_bit = value & 1 << i;
+            if check_bit > 0 {
+                one_bits[i] += 1;
+            } else {
+                zero_bits[i] += 1;
+            }
+        }
+    }
+
+    let mut epsilon = 0u32;
+    let mut gamma = 0u32;
+
+    for i in 0..32 {
+        // Assume that one bit is never all zeroes/all ones
+        if one_bits[i] > 0 && zero_bits[i] > 0 {
+            if one_bits[i] > zero_bits[i] {
+                gamma |= 1 << i;
+            }
+            if one_bits[i] < zero_bits[i] {
+                epsilon |= 1 << i;
+            }
+        }
+    }
+
+    return Result { epsilon, gamma };
+}
+
+/// Calculates the oxygen generator rating and CO2 scrubber rating by filtering the
+/// report bit by bit.
+pub fn calc_life_support_rating(report: &[u32]) -> (u32, u32) {
+    let mut values_1 = report.to_owned();
+    let mut values_2 = report.to_owned();
+
+    for i in (0..32).rev() {
+        if values_1.len() > 1 {
+            let (ones, zeroes): (Vec<u32>, Vec<u32>) =
+                values_1.iter().partition(|&v| (v & 1 << i) > 0);
+            if zeroes.len() > ones.len() {
+                values_1 = zeroes;
+            } else {
+                values_1 = ones;
+            }
+        }
+
+        if values_2.len() > 1 {
+            let (ones, zeroes): (Vec<u32>, Vec<u32>) =
+                values_2.iter().partition(|&v| (v & 1 << i) > 0);
+            if !ones.is_empty() && ones.len() < zeroes.len() {
+                values_2 = ones;
+            } else {
+                values_2 = zeroes;
+            }
+        }
+    }
+
+    assert_eq!(values_1.len(), 1);
+    assert_eq!(values_2.len(), 1);
+
+    return (values_1[0], values_2[0]);
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day03>();
+}
+
+#[test]
+fn test_calc_power_consumption() {
+    let result = calc_power_consumption(&[
+        0b00100u32, 0b11110u32, 0b10110u32, 0b10111u32, 0b10101u32, 0b01111u32, 0b00111u32,
+        0b11100u32, 0b10000u32, 0b11001u32, 0b00010u32, 0b01010u32,
+    ]);
+
+    assert_eq!(
+        result,
+        Result {
+            epsilon: 9,
+            gamma: 22,
+        }
+    );
+}
+
+#[test]
+fn test_calc_life_support_rating() {
+    let (oxygen_generator_rating, c02_scrubber_rating) = calc_life_support_rating(&[
+        0b00100u32, 0b11110u32, 0b10110u32, 0b10111u32, 0b10101u32, 0b01111u32, 0b00111u32,
+        0b11100u32, 0b10000u32, 0b11001u32, 0b00010u32, 0b01010u32,
+    ]);
+
+    assert_eq!(oxygen_generator_rating, 23);
+    assert_eq!(c02_scrubber_rating, 10);
+}
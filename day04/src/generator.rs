@@ -0,0 +1,51 @@
+//! Random bingo games.
+
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+/// Generates `100 * scale` boards of numbers below `100 * scale`, all of which are drawn.
+/// Boards that would win together with the last winning board are left out, so that the
+/// last winning board is unique.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let range = 100 * scale;
+    let mut drawn_numbers: Vec<usize> = (0..range).collect();
+    drawn_numbers.shuffle(rng);
+
+    let mut drawn_at = vec![0; range];
+    for (i, &n) in drawn_numbers.iter().enumerate() {
+        drawn_at[n] = i;
+    }
+
+    let boards: Vec<Vec<usize>> = (0..100 * scale)
+        .map(|_| index::sample(rng, range, 25).into_vec())
+        .collect();
+    let wins_at: Vec<usize> = boards
+        .iter()
+        .map(|board| {
+            let rows = (0..5).map(|r| (0..5).map(|c| drawn_at[board[r * 5 + c]]).max());
+            let columns = (0..5).map(|c| (0..5).map(|r| drawn_at[board[r * 5 + c]]).max());
+            rows.chain(columns).flatten().min().unwrap()
+        })
+        .collect();
+    let last_win = *wins_at.iter().max().unwrap();
+    let last_board = wins_at.iter().position(|&w| w == last_win).unwrap();
+
+    let mut text = drawn_numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    text.push('\n');
+    for (i, board) in boards.iter().enumerate() {
+        if wins_at[i] == last_win && i != last_board {
+            continue;
+        }
+        text.push('\n');
+        for row in board.chunks(5) {
+            let row = row.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>();
+            text.push_str(&row.join(" "));
+            text.push('\n');
+        }
+    }
+    return text;
+}
@@ -0,0 +1,302 @@
+//! Day 4: Giant Squid
+
+use common::answer::{Answer, Value};
+use common::{parse_lines, sections, Input, ParseError, Parser, Solution};
+use std::error::Error;
+use std::fmt;
+
+pub mod generator;
+
+/// Plays bingo against the giant squid.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = (Vec<i32>, Vec<BingoBoard>);
+    type Part1 = Score;
+    type Part2 = Score;
+
+    fn parse(text: &str) -> Result<(Vec<i32>, Vec<BingoBoard>), ParseError> {
+        let (drawn_numbers, boards) = parse_input(text)?;
+        // Both parts need a winner, so the drawn numbers must let every board win
+        if find_last_winning_board(&drawn_numbers, boards.clone()).is_none() {
+            let line = text.lines().next().unwrap_or(text);
+            let message = "drawn numbers do not let every board win";
+            return Err(ParseError::at(text, line, message));
+        }
+        Ok((drawn_numbers, boards))
+    }
+
+    fn part1((drawn_numbers, boards): &(Vec<i32>, Vec<BingoBoard>)) -> Score {
+        let (sum, last_number) = find_first_winning_board(drawn_numbers, boards.clone())
+            .expect("failed to find first winning board");
+        Score { sum, last_number }
+    }
+
+    fn part2((drawn_numbers, boards): &(Vec<i32>, Vec<BingoBoard>)) -> Score {
+        let (sum, last_number) = find_last_winning_board(drawn_numbers, boards.clone())
+            .expect("failed to find last winning board");
+        Score { sum, last_number }
+    }
+}
+
+/// Score of a winning board, the sum of its unmarked numbers times the last drawn number.
+#[derive(Debug, PartialEq)]
+pub struct Score {
+    pub sum: i32,
+    pub last_number: i32,
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.sum * self.last_number)
+    }
+}
+
+impl Answer for Score {
+    fn to_json(&self) -> Value {
+        Value::from(self.sum * self.last_number)
+    }
+
+    fn details(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("sum", Value::from(self.sum)),
+            ("last_number", Value::from(self.last_number)),
+        ]
+    }
+}
+
+/// Draws numbers until the first board wins and returns the sum of its unmarked numbers
+/// and the last drawn number.
+pub fn find_first_winning_board(
+    drawn_numbers: &[i32],
+    mut boards: Vec<BingoBoard>,
+) -> Option<(i32, i32)> {
+    for &n in drawn_numbers {
+        for board in boards.iter_mut() {
+            board.mark_number(n);
+
+            if board.is_done() {
+                let unmarked_numbers = board.get_unmarked_numbers();
+                let sum = unmarked_numbers.iter().sum();
+
+                return Some((sum, n));
+            }
+        }
+    }
+
+    return None;
+}
+
+/// Draws numbers until the last board wins and returns the sum of its unmarked numbers
+/// and the last drawn number.
+pub fn find_last_winning_board(
+    drawn_numbers: &[i32],
+    mut boards: Vec<BingoBoard>,
+) -> Option<(i32, i32)> {
+    for &n in drawn_numbers {
+        for board in boards.iter_mut() {
+            board.mark_number(n);
+        }
+
+        if boards.len() == 1 && boards[0].is_done() {
+            let board = &boards[0];
+            let unmarked_numbers = board.get_unmarked_numbers();
+            let sum = unmarked_numbers.iter().sum();
+
+            return Some((sum, n));
+        } else {
+            boards.retain(|b| !b.is_done());
+        }
+    }
+
+    return None;
+}
+
+/// 5x5 bingo board keeping track of marked numbers.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BingoBoard {
+    fields: [[(i32, bool); 5]; 5],
+}
+
+impl BingoBoard {
+    /// Creates a board without any marked numbers.
+    pub fn new(fields: [[i32; 5]; 5]) -> Self {
+        let mut marked_fields = [[(0, false); 5]; 5];
+
+        for i in 0..5 {
+            for h in 0..5 {
+                marked_fields[i][h] = (fields[i][h], false);
+            }
+        }
+
+        return BingoBoard {
+            fields: marked_fields,
+        };
+    }
+
+    /// Parses five lines of five numbers separated by whitespace.
+    pub fn parse(text: &str) -> Result<BingoBoard, ParseError> {
+        let rows = parse_lines(text, |line| {
+            let mut parser = Parser::new(line);
+            let mut row = [0; 5];
+            for number in row.iter_mut() {
+                parser.whitespace();
+                *number = parser.number()?;
+            }
+            parser.whitespace();
+            parser.end()?;
+            Ok(row)
+        })?;
+
+        let numbers = rows
+            .try_into()
+            .map_err(|_| ParseError::at(text, text, "expected five rows of five numbers"))?;
+        return Ok(BingoBoard::new(numbers));
+    }
+
+    /// Returns whether all numbers of a row or column are marked.
+    pub fn is_done(&self) -> bool {
+        'outer_rows: for i in 0..5 {
+            for h in 0..5 {
+                let (_, marked) = self.fields[i][h];
+                if !marked {
+                    continue 'outer_rows;
+                }
+            }
+
+            return true;
+        }
+
+        'outer_cols: for i in 0..5 {
+            for h in 0..5 {
+                let (_, marked) = self.fields[h][i];
+                if !marked {
+                    continue 'outer_cols;
+                }
+            }
+
+            return true;
+        }
+
+        return false;
+    }
+
+    /// Marks `number` wherever it is on the board.
+    pub fn mark_number(&mut self, number: i32) {
+        for i in 0..5 {
+            for h in 0..5 {
+                let (field_number, _) = self.fields[i][h];
+                if number == field_number {
+                    self.fields[i][h] = (number, true);
+                }
+            }
+        }
+    }
+
+    /// Returns all numbers that are not marked yet.
+    pub fn get_unmarked_numbers(&self) -> Vec<i32> {
+        let mut numbers = Vec::new();
+        for i in 0..5 {
+            for h in 0..5 {
+                let (number, marked) = self.fields[i][h];
+                if !marked {
+                    numbers.push(number);
+                }
+            }
+        }
+
+        return numbers;
+    }
+}
+
+impl fmt::Display for BingoBoard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in &self.fields {
+            let numbers: Vec<_> = row.iter().map(|(n, _)| format!("{:>2}", n)).collect();
+            writeln!(f, "{}", numbers.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the drawn numbers and bingo boards from `input`.
+pub fn get_input(input: &Input) -> Result<(Vec<i32>, Vec<BingoBoard>), Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses the comma separated drawn numbers followed by the bingo boards, each preceded
+/// by an empty line.
+pub fn parse_input(text: &str) -> Result<(Vec<i32>, Vec<BingoBoard>), ParseError> {
+    let mut sections = sections(text);
+    let mut parser = Parser::new(sections.next().unwrap_or_default());
+    let drawn_numbers = parser.list(",", Parser::number)?;
+    parser.end()?;
+    let boards = sections
+        .map(|section| BingoBoard::parse(section).map_err(|e| e.within(text, section)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    return Ok((drawn_numbers, boards));
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day04>();
+}
+
+#[test]
+fn test_no_winner() {
+    let example = common::fixtures::fixture(4, "example");
+    let (numbers, boards) = example.split_once("\n").unwrap();
+    let err = Day04::parse(&format!("1,2,3\n{}", boards)).unwrap_err();
+    assert_eq!((err.line, err.column), (1, 1));
+    assert_eq!(err.message, "drawn numbers do not let every board win");
+    assert!(Day04::parse(numbers).is_err());
+}
+
+#[test]
+fn test_parse() {
+    let board_text = "64 19 39 69 90
+41  5 59 37 42
+75 95 58 89 92
+20  3 85 48 71
+31 94 11 18 70";
+    let board = BingoBoard::parse(board_text).unwrap();
+
+    assert_eq!(
+        board,
+        BingoBoard::new([
+            [64, 19, 39, 69, 90],
+            [41, 5, 59, 37, 42],
+            [75, 95, 58, 89, 92],
+            [20, 3, 85, 48, 71],
+            [31, 94, 11, 18, 70]
+        ])
+    );
+}
+
+#[test]
+fn test_parse_whitespace() {
+    let board = BingoBoard::parse(
+        "1 2 3 4 5\n\t6  7 8 9 10 \n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25",
+    );
+    assert_eq!(
+        board.unwrap().get_unmarked_numbers(),
+        (1..=25).collect::<Vec<_>>()
+    );
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_display_round_trip(fields: [[i32; 5]; 5]) {
+        let board = BingoBoard::new(fields);
+        proptest::prop_assert_eq!(BingoBoard::parse(&board.to_string()), Ok(board));
+    }
+
+    #[test]
+    fn test_parse_never_panics(text in "[0-9 ,\\n-]{0,64}|\\PC{0,16}") {
+        let _ = parse_input(&text);
+    }
+}
@@ -0,0 +1,51 @@
+//! Picture of the lines of vents and where they overlap.
+
+use crate::{parse_input, Line};
+use common::svg::{Style, Svg};
+use common::ParseError;
+use std::collections::BTreeMap;
+
+/// Returns the points covered by `line`, which is horizontal, vertical or diagonal.
+fn points(line: &Line) -> impl Iterator<Item = (i32, i32)> {
+    let ((x1, y1), (x2, y2)) = (line.from, line.to);
+    let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+    let length = (x2 - x1).abs().max((y2 - y1).abs());
+    (0..=length).map(move |i| (x1 + i * dx, y1 + i * dy))
+}
+
+/// Draws the lines of vents in `text`, marking the points where at least two of them
+/// overlap.
+pub fn drawing(text: &str) -> Result<Svg, ParseError> {
+    let lines = parse_input(text)?;
+    let (max_x, max_y) = lines
+        .iter()
+        .flat_map(|line| [line.from, line.to])
+        .fold((0, 0), |(mx, my), (x, y)| (mx.max(x), my.max(y)));
+
+    let mut covered: BTreeMap<(i32, i32), usize> = BTreeMap::new();
+    for line in &lines {
+        for point in points(line) {
+            *covered.entry(point).or_default() += 1;
+        }
+    }
+
+    let mut svg = Svg::new(-1.0, -1.0, max_x as f64 + 3.0, max_y as f64 + 3.0);
+    let line_style = Style::stroke("#4575b4", 1.0).with_opacity(0.6);
+    for line in &lines {
+        let center = |(x, y): (i32, i32)| (x as f64 + 0.5, y as f64 + 0.5);
+        svg.line(center(line.from), center(line.to), &line_style);
+    }
+    let overlaps = covered.values().filter(|&&count| count >= 2).count();
+    for (&(x, y), &count) in &covered {
+        if count >= 2 {
+            svg.cell(x as f64, y as f64, "#d73027");
+        }
+    }
+    svg.text(
+        0.0,
+        -1.0 + 16.0 * svg.pixel(),
+        &format!("{} lines, {} overlaps", lines.len(), overlaps),
+        "black",
+    );
+    return Ok(svg);
+}
@@ -0,0 +1,46 @@
+//! Random vent lines.
+
+use rand::Rng;
+
+/// Generates `500 * scale` horizontal, vertical and diagonal lines on a 1000 by 1000
+/// floor. The lines are mirrored along the diagonal if needed so that they extend at
+/// least as far in `x` as in `y`, which [`crate::determine_overlaps`] relies on.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    const SIZE: i32 = 1000;
+
+    let mut lines = Vec::new();
+    for _ in 0..500 * scale {
+        let (x1, y1) = (rng.gen_range(0..SIZE), rng.gen_range(0..SIZE));
+        let (dx, dy) = match rng.gen_range(0..3) {
+            0 => (1, 0),
+            1 => (0, 1),
+            _ => (1, if rng.gen() { 1 } else { -1 }),
+        };
+        let (dx, dy) = if rng.gen() { (dx, dy) } else { (-dx, -dy) };
+        let max_length = [(x1, dx), (y1, dy)]
+            .iter()
+            .map(|&(p, d)| match d {
+                1 => SIZE - 1 - p,
+                -1 => p,
+                _ => SIZE,
+            })
+            .min()
+            .unwrap();
+        let length = rng.gen_range(0..=max_length.min(SIZE / 2));
+        lines.push((x1, y1, x1 + dx * length, y1 + dy * length));
+    }
+
+    let max_x = lines.iter().map(|l| l.0.max(l.2)).max().unwrap_or(0);
+    let max_y = lines.iter().map(|l| l.1.max(l.3)).max().unwrap_or(0);
+    if max_x < max_y {
+        for line in &mut lines {
+            *line = (line.1, line.0, line.3, line.2);
+        }
+    }
+
+    let mut text = String::new();
+    for (x1, y1, x2, y2) in lines {
+        text.push_str(&format!("{},{} -> {},{}\n", x1, y1, x2, y2));
+    }
+    return text;
+}
@@ -0,0 +1,136 @@
+//! Day 5: Hydrothermal Venture
+
+use common::{parse_lines, Input, ParseError, Parser, Solution};
+use std::cmp::{max, min};
+use std::error::Error;
+
+pub mod draw;
+pub mod generator;
+
+/// Counts the points where lines of hydrothermal vents overlap.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<Line>, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(lines: &Vec<Line>) -> usize {
+        determine_overlaps(lines, false)
+    }
+
+    fn part2(lines: &Vec<Line>) -> usize {
+        determine_overlaps(lines, true)
+    }
+}
+
+/// Point on the ocean floor as `(x, y)`.
+pub type Point = (i32, i32);
+
+/// Line of hydrothermal vents, including both end points.
+pub struct Line {
+    pub from: Point,
+    pub to: Point,
+}
+
+/// Reads the lines of vents from `input`.
+pub fn get_input(input: &Input) -> Result<Vec<Line>, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses one line like `0,9 -> 5,9` per line.
+pub fn parse_input(text: &str) -> Result<Vec<Line>, ParseError> {
+    fn point(parser: &mut Parser) -> Result<Point, ParseError> {
+        parser.separated(",", Parser::number, Parser::number)
+    }
+
+    parse_lines(text, |line| {
+        let mut parser = Parser::new(line);
+        let (from, to) = parser.separated(" -> ", point, point)?;
+        parser.end()?;
+        return Ok(Line { from, to });
+    })
+}
+
+/// Counts the points covered by at least two lines. Diagonal lines are ignored unless
+/// `include_diagonal` is set.
+pub fn determine_overlaps(lines: &[Line], include_diagonal: bool) -> usize {
+    let mut max_x = 0;
+    let mut max_y = 0;
+
+    for line in lines {
+        let (x1, y1) = line.from;
+        let (x2, y2) = line.to;
+
+        max_x = max(max_x, max(x1, x2));
+        max_y = max(max_y, max(y1, y2));
+    }
+
+    let mut world: Vec<i32> = vec![0; ((max_x + 1) * (max_y + 1)) as usize];
+
+    for line in lines {
+        let (x1, y1) = line.from;
+        let (x2, y2) = line.to;
+
+        if x1 == x2 {
+            let start = min(y1, y2);
+            let end = max(y1, y2);
+            for y in start..end + 1 {
+                world[(y * max_y + x1) as usize] += 1;
+            }
+        } else if y1 == y2 {
+            let start = min(x1, x2);
+            let end = max(x1, x2);
+            for x in start..end + 1 {
+                world[(y1 * max_y + x) as usize] += 1;
+            }
+        } else if include_diagonal {
+            if x1 < x2 {
+                let mut y = y1;
+                for x in x1..x2 + 1 {
+                    world[(y * max_y + x) as usize] += 1;
+                    if y1 < y2 {
+                        y += 1
+                    } else {
+                        y -= 1
+                    }
+                }
+            } else {
+                let mut y = y2;
+                for x in x2..x1 + 1 {
+                    world[(y * max_y + x) as usize] += 1;
+                    if y2 < y1 {
+                        y += 1
+                    } else {
+                        y -= 1
+                    }
+                }
+            }
+        }
+    }
+
+    return world.iter().filter(|&&n| n >= 2).count();
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day05>();
+}
+
+#[test]
+fn test_determine_overlaps() {
+    let lines = parse_input(&common::fixtures::fixture(5, "example")).unwrap();
+    assert_eq!(determine_overlaps(&lines, false), 5);
+}
+
+#[test]
+fn test_determine_overlaps_including_diagonals() {
+    let lines = parse_input(&common::fixtures::fixture(5, "example")).unwrap();
+    assert_eq!(determine_overlaps(&lines, true), 12);
+}
@@ -0,0 +1,12 @@
+//! Random lanternfish schools.
+
+use rand::Rng;
+
+/// Generates the timers of `300 * scale` lanternfishes, between 1 and 5 like in the
+/// puzzle.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let timers: Vec<String> = (0..300 * scale)
+        .map(|_| rng.gen_range(1..=5).to_string())
+        .collect();
+    return timers.join(",") + "\n";
+}
@@ -0,0 +1,102 @@
+//! Day 6: Lanternfish
+
+use common::{parse_number, Input, ParseError, Solution};
+use std::error::Error;
+
+pub mod generator;
+pub mod visualize;
+
+/// Simulates the growth of the lanternfish population.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(text: &str) -> Result<Vec<u64>, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(timers: &Vec<u64>) -> u64 {
+        simulate_laternfishes(&LookupTable::init(80), timers, 80)
+    }
+
+    fn part2(timers: &Vec<u64>) -> u64 {
+        simulate_laternfishes(&LookupTable::init(256), timers, 256)
+    }
+}
+
+/// Reads the initial timers of the lanternfishes from `input`.
+pub fn get_input(input: &Input) -> Result<Vec<u64>, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses the comma separated timers.
+pub fn parse_input(text: &str) -> Result<Vec<u64>, ParseError> {
+    return text
+        .trim()
+        .split(",")
+        .map(|s| parse_number(text, s))
+        .collect();
+}
+
+/// Counts the lanternfishes after `days`, using `table` that was initialized for at least
+/// as many days.
+pub fn simulate_laternfishes(table: &LookupTable, timers: &[u64], days: u64) -> u64 {
+    return timers.iter().map(|&t| table.get(t, days)).sum();
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day06>();
+}
+
+#[test]
+fn test_simulate_laternfishes() {
+    let table = LookupTable::init(256);
+    let timers = vec![3, 4, 3, 1, 2];
+    assert_eq!(simulate_laternfishes(&table, &timers, 18), 26);
+    assert_eq!(simulate_laternfishes(&table, &timers, 80), 5934);
+    assert_eq!(simulate_laternfishes(&table, &timers, 256), 26984457539);
+}
+
+/// Number of lanternfishes a single fish turns into, by initial timer and number of days.
+pub struct LookupTable {
+    table: Vec<u64>,
+}
+
+impl LookupTable {
+    /// Precomputes the table for up to `days` days.
+    pub fn init(days: u64) -> LookupTable {
+        let mut table = vec![0u64; (days as usize + 1) * 9];
+
+        fn get(table: &[u64], timer: u64, days: u64) -> u64 {
+            return table[(days * 9 + timer) as usize];
+        }
+
+        for timer in 0..9 {
+            table[timer] = 1;
+        }
+
+        for day in 1..days + 1 {
+            for timer in 0..9 {
+                if timer == 0 {
+                    table[(day * 9 + timer) as usize] =
+                        get(&table, 6, day - 1) + get(&table, 8, day - 1);
+                } else {
+                    table[(day * 9 + timer) as usize] = get(&table, timer - 1, day - 1)
+                }
+            }
+        }
+
+        return LookupTable { table };
+    }
+
+    /// Returns the number of lanternfishes a fish with `timer` turns into after `days`.
+    pub fn get(&self, timer: u64, days: u64) -> u64 {
+        return self.table[(days * 9 + timer) as usize];
+    }
+}
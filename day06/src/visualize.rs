@@ -0,0 +1,66 @@
+//! Animation of the population growth.
+
+use crate::parse_input;
+use common::visualize::{Color, Frame, Simulation};
+use common::ParseError;
+
+/// Number of days the population is simulated for, as in part 2.
+const DAYS: u64 = 256;
+
+/// Width of the longest bar of the histogram.
+const BAR_WIDTH: usize = 60;
+
+/// Number of lanternfishes by timer, advanced one day per step.
+pub struct Population {
+    counts: [u64; 9],
+    day: u64,
+}
+
+impl Simulation for Population {
+    fn step(&mut self) -> bool {
+        if self.day == DAYS {
+            return false;
+        }
+        // Fishes at zero reset to six and each create a new fish at eight
+        self.counts.rotate_left(1);
+        self.counts[6] += self.counts[8];
+        self.day += 1;
+        true
+    }
+
+    fn render(&self) -> Frame {
+        let total: u64 = self.counts.iter().sum();
+        let status = format!("day {} of {}, {} lanternfishes", self.day, DAYS, total);
+        let mut frame = Frame::new(status, 0, 0);
+
+        let max = self.counts.iter().copied().max().unwrap_or(0).max(1);
+        for (timer, &count) in self.counts.iter().enumerate() {
+            let length = (count as u128 * BAR_WIDTH as u128 / max as u128) as usize;
+            let color = if timer == 0 {
+                Color::Yellow
+            } else {
+                Color::Cyan
+            };
+            frame.push_text(
+                &format!(
+                    "{} {:<width$} {}",
+                    timer,
+                    "#".repeat(length),
+                    count,
+                    width = BAR_WIDTH
+                ),
+                color,
+            );
+        }
+        frame
+    }
+}
+
+/// Creates the simulation of the lanternfishes with the timers in `text`.
+pub fn simulation(text: &str) -> Result<Box<dyn Simulation>, ParseError> {
+    let mut counts = [0; 9];
+    for timer in parse_input(text)? {
+        counts[timer.min(8) as usize] += 1;
+    }
+    Ok(Box::new(Population { counts, day: 0 }))
+}
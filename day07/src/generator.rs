@@ -0,0 +1,21 @@
+//! Random crab positions.
+
+use rand::Rng;
+
+/// Generates the positions of `1000 * scale` crabs. Most crabs gather around a few
+/// spots below 2000 while some are spread out evenly.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let spots: Vec<i32> = (0..5).map(|_| rng.gen_range(0..2000)).collect();
+    let positions: Vec<String> = (0..1000 * scale)
+        .map(|_| {
+            if rng.gen_bool(0.8) {
+                let spot = spots[rng.gen_range(0..spots.len())];
+                (spot + rng.gen_range(-200..=200)).clamp(0, 1999)
+            } else {
+                rng.gen_range(0..2000)
+            }
+        })
+        .map(|p| p.to_string())
+        .collect();
+    return positions.join(",") + "\n";
+}
@@ -0,0 +1,117 @@
+//! Day 7: The Treachery of Whales
+
+use common::answer::{Answer, Value};
+use common::{parse_number, Input, ParseError, Solution};
+use std::error::Error;
+use std::fmt;
+
+pub mod generator;
+
+/// Aligns the crab submarines at the position that costs the least fuel.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<i32>;
+    type Part1 = Alignment;
+    type Part2 = Alignment;
+
+    fn parse(text: &str) -> Result<Vec<i32>, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(positions: &Vec<i32>) -> Alignment {
+        let (position, fuel) = find_cheapest_alignment(positions, false);
+        Alignment { position, fuel }
+    }
+
+    fn part2(positions: &Vec<i32>) -> Alignment {
+        let (position, fuel) = find_cheapest_alignment(positions, true);
+        Alignment { position, fuel }
+    }
+}
+
+/// Position the crabs align at and the fuel it costs, which is the answer.
+#[derive(Debug, PartialEq)]
+pub struct Alignment {
+    pub position: i32,
+    pub fuel: i32,
+}
+
+impl fmt::Display for Alignment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.fuel)
+    }
+}
+
+impl Answer for Alignment {
+    fn to_json(&self) -> Value {
+        Value::from(self.fuel)
+    }
+
+    fn details(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("position", Value::from(self.position)),
+            ("fuel", Value::from(self.fuel)),
+        ]
+    }
+}
+
+/// Reads the horizontal positions of the crabs from `input`.
+pub fn get_input(input: &Input) -> Result<Vec<i32>, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses the comma separated positions.
+pub fn parse_input(text: &str) -> Result<Vec<i32>, ParseError> {
+    return text
+        .trim()
+        .split(",")
+        .map(|s| parse_number(text, s))
+        .collect();
+}
+
+/// Returns the cheapest position to align at and its fuel costs. Each step costs one more
+/// fuel than the previous one if `part2` is set, otherwise every step costs one fuel.
+pub fn find_cheapest_alignment(positions: &[i32], part2: bool) -> (i32, i32) {
+    let &max = positions.iter().max().unwrap_or(&1);
+    let mut cheapest_pos = 0;
+    let mut cheapest_costs = None;
+    for i in 0..max + 1 {
+        let costs: i32 = positions
+            .iter()
+            .map(|p| i32::abs(i - p))
+            .map(|c| -> i32 {
+                if part2 {
+                    ((c + 1) as f32 / 2.0 * (c as f32)) as i32
+                } else {
+                    c
+                }
+            })
+            .sum();
+        if cheapest_costs.is_none() || cheapest_costs.unwrap() > costs {
+            cheapest_pos = i;
+            cheapest_costs = Some(costs);
+        }
+    }
+
+    return (cheapest_pos, cheapest_costs.unwrap());
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day07>();
+}
+
+#[test]
+fn test_find_cheapest_alignment() {
+    assert_eq!(
+        find_cheapest_alignment(&[16, 1, 2, 0, 4, 2, 7, 1, 2, 14], false),
+        (2, 37)
+    );
+    assert_eq!(
+        find_cheapest_alignment(&[16, 1, 2, 0, 4, 2, 7, 1, 2, 14], true),
+        (5, 168)
+    );
+}
@@ -0,0 +1,33 @@
+//! Random seven-segment display notes.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Segments of the digits 0 to 9 on a correctly wired display.
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Generates notes of `200 * scale` displays, each with its own random wiring.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..200 * scale {
+        let mut wiring: Vec<char> = ('a'..='g').collect();
+        wiring.shuffle(rng);
+        let digits: Vec<usize> = (0..4).map(|_| rng.gen_range(0..10)).collect();
+        let mut encode = |digit: usize| {
+            let mut signals: Vec<char> = DIGITS[digit]
+                .chars()
+                .map(|c| wiring[c as usize - 'a' as usize])
+                .collect();
+            signals.shuffle(rng);
+            signals.into_iter().collect::<String>()
+        };
+
+        let mut patterns: Vec<String> = (0..10).map(&mut encode).collect();
+        let output: Vec<String> = digits.into_iter().map(&mut encode).collect();
+        patterns.shuffle(rng);
+        text.push_str(&format!("{} | {}\n", patterns.join(" "), output.join(" ")));
+    }
+    return text;
+}
@@ -0,0 +1,254 @@
+//! Day 8: Seven Segment Search
+
+use common::{parse_lines, Input, ParseError, Solution};
+use std::error::Error;
+
+pub mod generator;
+
+/// Decodes the scrambled seven-segment displays.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Entry>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(text: &str) -> Result<Vec<Entry>, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(entries: &Vec<Entry>) -> usize {
+        count_digits_1478(entries)
+    }
+
+    fn part2(entries: &Vec<Entry>) -> i32 {
+        entries.iter().map(decode_output).sum()
+    }
+}
+
+/// Counts the output digits that are a 1, 4, 7 or 8, which are the only digits with a
+/// unique number of segments.
+pub fn count_digits_1478(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .flat_map(|e| e.output.into_iter())
+        .map(|s| count_signals(&s))
+        .filter(|&count| count == 2 || count == 3 || count == 4 || count == 7)
+        .count()
+}
+
+/// Segments `a` to `g` that are turned on.
+pub type Signals = (bool, bool, bool, bool, bool, bool, bool);
+
+/// Counts the segments that are turned on.
+pub fn count_signals(signals: &Signals) -> i32 {
+    let &(a, b, c, d, e, f, g) = signals;
+
+    let mut count = 0;
+    if a {
+        count += 1;
+    }
+    if b {
+        count += 1;
+    }
+    if c {
+        count += 1;
+    }
+    if d {
+        count += 1;
+    }
+    if e {
+        count += 1;
+    }
+    if f {
+        count += 1;
+    }
+    if g {
+        count += 1;
+    }
+
+    return count;
+}
+
+/// Deduces the wiring from the ten unique patterns and decodes the four digit output.
+pub fn decode_output(entry: &Entry) -> i32 {
+    use std::collections::HashMap;
+
+    let mut codes = HashMap::new();
+
+    // find codes by number of signals (see task 1)
+    let code_1 = entry
+        .patterns
+        .into_iter()
+        .find(|s| count_signals(s) == 2)
+        .unwrap();
+    let code_4 = entry
+        .patterns
+        .into_iter()
+        .find(|s| count_signals(s) == 4)
+        .unwrap();
+    let code_7 = entry
+        .patterns
+        .into_iter()
+        .find(|s| count_signals(s) == 3)
+        .unwrap();
+    let code_8 = entry
+        .patterns
+        .into_iter()
+        .find(|s| count_signals(s) == 7)
+        .unwrap();
+
+    let code_9 = entry
+        .patterns
+        .into_iter()
+        .filter(|s| count_signals(s) == 6)
+        .find(|(a, b, c, d, e, f, g)| -> bool {
+            let (a4, b4, c4, d4, e4, f4, g4) = code_4;
+
+            return (!a && !a4)
+                || (!b && !b4)
+                || (!c && !c4)
+                || (!d && !d4)
+                || (!e && !e4)
+                || (!f && !f4)
+                || (!g && !g4);
+        })
+        .unwrap();
+
+    let code_6 = entry
+        .patterns
+        .into_iter()
+        .filter(|s| count_signals(s) == 6)
+        .find(|(a, b, c, d, e, f, g)| -> bool {
+            let (a1, b1, c1, d1, e1, f1, g1) = code_1;
+
+            return (!a && a1)
+                || (!b && b1)
+                || (!c && c1)
+                || (!d && d1)
+                || (!e && e1)
+                || (!f && f1)
+                || (!g && g1);
+        })
+        .unwrap();
+
+    let code_0 = entry
+        .patterns
+        .into_iter()
+        .find(|&s| count_signals(&s) == 6 && s != code_6 && s != code_9)
+        .unwrap();
+
+    let code_5 = entry
+        .patterns
+        .into_iter()
+        .filter(|s| count_signals(s) == 5)
+        .find(|(a, b, c, d, e, f, g)| -> bool {
+            let (a6, b6, c6, d6, e6, f6, g6) = code_6;
+
+            return (!a || a6)
+                && (!b || b6)
+                && (!c || c6)
+                && (!d || d6)
+                && (!e || e6)
+                && (!f || f6)
+                && (!g || g6);
+        })
+        .unwrap();
+    let code_3 = entry
+        .patterns
+        .into_iter()
+        .filter(|&s| count_signals(&s) == 5 && s != code_5)
+        .find(|(a, b, c, d, e, f, g)| -> bool {
+            let (a9, b9, c9, d9, e9, f9, g9) = code_9;
+
+            return (!a || a9)
+                && (!b || b9)
+                && (!c || c9)
+                && (!d || d9)
+                && (!e || e9)
+                && (!f || f9)
+                && (!g || g9);
+        })
+        .unwrap();
+
+    // Last remaining with 5 1s
+    let code_2 = entry
+        .patterns
+        .into_iter()
+        .find(|&s| count_signals(&s) == 5 && s != code_3 && s != code_5)
+        .unwrap();
+
+    codes.insert(code_0, 0);
+    codes.insert(code_1, 1);
+    codes.insert(code_2, 2);
+    codes.insert(code_3, 3);
+    codes.insert(code_4, 4);
+    codes.insert(code_5, 5);
+    codes.insert(code_6, 6);
+    codes.insert(code_7, 7);
+    codes.insert(code_8, 8);
+    codes.insert(code_9, 9);
+
+    let mut output = 0;
+    for signals in entry.output.into_iter() {
+        output = output * 10 + codes.get(&signals).unwrap();
+    }
+
+    output
+}
+
+/// Notes of a single display: all ten patterns and the four output digits.
+pub struct Entry {
+    pub patterns: [Signals; 10],
+    pub output: [Signals; 4],
+}
+
+/// Reads the notes of all displays from `input`.
+pub fn get_input(input: &Input) -> Result<Vec<Entry>, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses one entry like `acedgfb cdfbe ... | cdfeb fcadb cdfeb cdbaf` per line.
+pub fn parse_input(text: &str) -> Result<Vec<Entry>, ParseError> {
+    fn parse_signals(line: &str, text: &str) -> Result<Signals, ParseError> {
+        if text.is_empty() || !text.chars().all(|c| ('a'..='g').contains(&c)) {
+            return Err(ParseError::at(line, text, "expected segments `a` to `g`"));
+        }
+
+        let a = text.contains("a");
+        let b = text.contains("b");
+        let c = text.contains("c");
+        let d = text.contains("d");
+        let e = text.contains("e");
+        let f = text.contains("f");
+        let g = text.contains("g");
+
+        return Ok((a, b, c, d, e, f, g));
+    }
+
+    fn parse_patterns<const N: usize>(line: &str, text: &str) -> Result<[Signals; N], ParseError> {
+        text.split(" ")
+            .map(|s| parse_signals(line, s))
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .map_err(|_| ParseError::at(line, text, &format!("expected {} patterns", N)))
+    }
+
+    parse_lines(text.trim(), |line| {
+        let (patterns, output) = line.split_once(" | ").ok_or_else(|| {
+            ParseError::at(line, line, "expected patterns and output separated by `|`")
+        })?;
+
+        Ok(Entry {
+            patterns: parse_patterns(line, patterns)?,
+            output: parse_patterns(line, output)?,
+        })
+    })
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day08>();
+}
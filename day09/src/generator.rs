@@ -0,0 +1,49 @@
+//! Random heightmaps.
+
+use crate::Map;
+use grid::Grid;
+use rand::Rng;
+
+/// Side length of the square each basin's low point is placed in.
+const BASIN: i32 = 9;
+
+/// Generates a heightmap of `100 * scale` by `100 * scale` points. It is divided into
+/// basins around randomly placed low points, one per square of [`BASIN`] points, with
+/// heights rising towards ridges of 9 between them.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let size = 100 * scale as i32;
+    let blocks = (size + BASIN - 1) / BASIN;
+    let low_points: Grid<(i32, i32)> = Grid::from_fn(blocks, blocks, |bx, by| {
+        let x = (bx * BASIN + rng.gen_range(0..BASIN)).min(size - 1);
+        let y = (by * BASIN + rng.gen_range(0..BASIN)).min(size - 1);
+        (x, y)
+    });
+
+    // Each point belongs to the basin of the closest low point in the surrounding squares
+    let basins = Grid::from_fn(size, size, |x, y| {
+        let (bx, by) = (x / BASIN, y / BASIN);
+        let mut candidates = Vec::new();
+        for (dx, dy) in grid::NEIGHBOURS_8.iter().chain(&[(0, 0)]) {
+            if let Some(&(lx, ly)) = low_points.get(bx + dx, by + dy) {
+                candidates.push((lx, ly));
+            }
+        }
+        candidates
+            .into_iter()
+            .min_by_key(|&(lx, ly)| (lx - x).pow(2) + (ly - y).pow(2))
+            .unwrap()
+    });
+
+    let map: Map = Grid::from_fn(size, size, |x, y| {
+        let basin = basins[(x, y)];
+        let border = [(1, 0), (0, 1)]
+            .iter()
+            .any(|&(dx, dy)| basins.get(x + dx, y + dy).is_some_and(|&b| b != basin));
+        if border {
+            9
+        } else {
+            ((basin.0 - x).abs() + (basin.1 - y).abs()).min(8) as u8
+        }
+    });
+    return map.to_string();
+}
@@ -0,0 +1,113 @@
+//! Day 9: Smoke Basin
+
+use common::{Input, ParseError, Solution};
+use grid::Grid;
+use std::error::Error;
+
+pub mod generator;
+
+/// Finds the low points and basins of the heightmap.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Map;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(text: &str) -> Result<Map, ParseError> {
+        let map = parse_input(text)?;
+        // Each low point is the bottom of one basin
+        if find_low_points(&map).len() < 3 {
+            return Err(ParseError::at_end(text, "expected at least three basins"));
+        }
+        Ok(map)
+    }
+
+    fn part1(map: &Map) -> i32 {
+        find_sum_of_risk_levels(map)
+    }
+
+    fn part2(map: &Map) -> i32 {
+        find_three_largest_basin_sizes(map)
+    }
+}
+
+/// Sums the risk levels, one more than the height, of all low points.
+pub fn find_sum_of_risk_levels(map: &Map) -> i32 {
+    return find_low_points(map)
+        .iter()
+        .map(|&(x, y)| map[(x, y)] as i32 + 1)
+        .sum();
+}
+
+/// Multiplies the sizes of the three largest basins.
+pub fn find_three_largest_basin_sizes(map: &Map) -> i32 {
+    use std::collections::BinaryHeap;
+
+    let mut sizes: BinaryHeap<_> = find_low_points(map)
+        .iter()
+        .map(|&(x, y)| get_basin_size(map, x, y))
+        .collect();
+
+    // return sizes.into_iter_sorted().take(3).sum();
+    return sizes.pop().unwrap() * sizes.pop().unwrap() * sizes.pop().unwrap();
+}
+
+/// Returns the points that are lower than all of their four neighbours.
+pub fn find_low_points(map: &Map) -> Vec<(i32, i32)> {
+    return map
+        .positions()
+        .filter(|&(x, y)| {
+            let height = map[(x, y)];
+            map.neighbours4(x, y).all(|n| height < map[n])
+        })
+        .collect();
+}
+
+/// Counts the points of the basin around `(x, y)`, which is bounded by points of height 9.
+pub fn get_basin_size(map: &Map, x: i32, y: i32) -> i32 {
+    use std::collections::HashSet;
+
+    let mut size = 0;
+    let mut checked = HashSet::new();
+    let mut to_check = vec![(x, y)];
+    while let Some((x, y)) = to_check.pop() {
+        if map[(x, y)] < 9 && checked.insert((x, y)) {
+            size += 1;
+            to_check.extend(map.neighbours4(x, y));
+        }
+    }
+
+    return size;
+}
+
+/// Heightmap of the cave floor.
+pub type Map = Grid<u8>;
+
+/// Reads the heightmap from `input`.
+pub fn get_input(input: &Input) -> Result<Map, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses one row of single digit heights per line.
+pub fn parse_input(text: &str) -> Result<Map, ParseError> {
+    Grid::parse_digits(text)
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day09>();
+}
+
+#[test]
+fn test_example() {
+    let map = parse_input(&common::fixtures::fixture(9, "example")).unwrap();
+    assert_eq!(find_sum_of_risk_levels(&map), 15);
+    assert_eq!(find_three_largest_basin_sizes(&map), 1134);
+
+    let err = Day09::parse("1239\n9999\n9129\n").unwrap_err();
+    assert_eq!(err.message, "expected at least three basins");
+    assert!(Day09::parse("").is_err());
+}
@@ -0,0 +1,58 @@
+//! Random navigation subsystems.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Deepest nesting of an incomplete line, keeping its autocomplete score within a `u64`.
+const MAX_DEPTH: usize = 25;
+
+/// Generates `100 * scale` lines, each either corrupted or incomplete. The number of
+/// incomplete lines is odd, so their scores have a middle one.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let count = 100 * scale;
+    let mut incomplete = vec![false; count];
+    for line in incomplete.iter_mut().take((count / 2) | 1) {
+        *line = true;
+    }
+    incomplete.shuffle(rng);
+
+    let mut text = String::new();
+    for incomplete in incomplete {
+        let length = rng.gen_range(20..110);
+        let mut corrupt_at = if incomplete {
+            None
+        } else {
+            Some(rng.gen_range(0..length))
+        };
+
+        let mut stack = vec![];
+        for i in 0..length {
+            if corrupt_at.is_some_and(|at| i >= at) && !stack.is_empty() {
+                let expected = stack.pop().unwrap();
+                let wrong: Vec<char> = PAIRS
+                    .iter()
+                    .map(|&(_, close)| close)
+                    .filter(|&close| close != expected)
+                    .collect();
+                text.push(*wrong.choose(rng).unwrap());
+                corrupt_at = None;
+            } else if stack.is_empty() || (stack.len() < MAX_DEPTH && rng.gen_bool(0.55)) {
+                let (open, close) = PAIRS[rng.gen_range(0..PAIRS.len())];
+                text.push(open);
+                stack.push(close);
+            } else {
+                text.push(stack.pop().unwrap());
+            }
+        }
+
+        if corrupt_at.is_some() {
+            text.push_str("(]");
+        } else if incomplete && stack.is_empty() {
+            text.push('(');
+        }
+        text.push('\n');
+    }
+    return text;
+}
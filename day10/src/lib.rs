@@ -0,0 +1,179 @@
+//! Day 10: Syntax Scoring
+
+use common::{parse_lines, Input, ParseError, Solution};
+use std::error::Error;
+
+pub mod generator;
+
+/// Checks the navigation subsystem for corrupted and incomplete lines.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<String>;
+    type Part1 = i32;
+    type Part2 = u64;
+
+    fn parse(text: &str) -> Result<Vec<String>, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(lines: &Vec<String>) -> i32 {
+        get_error_score(lines)
+    }
+
+    fn part2(lines: &Vec<String>) -> u64 {
+        median(&get_auto_complete_scores(lines))
+    }
+}
+
+/// Sums the scores of the first illegal character of every corrupted line.
+pub fn get_error_score<T>(lines: &[T]) -> i32
+where
+    T: AsRef<str>,
+{
+    lines
+        .iter()
+        .map(|l| check_line(l.as_ref()))
+        .map(|e| match e {
+            Some(SyntaxError::IllegalCharacter(')')) => 3,
+            Some(SyntaxError::IllegalCharacter(']')) => 57,
+            Some(SyntaxError::IllegalCharacter('}')) => 1197,
+            Some(SyntaxError::IllegalCharacter('>')) => 25137,
+            None | Some(SyntaxError::MissingCharacters(_)) => 0,
+            _ => panic!("invalid character"),
+        })
+        .sum()
+}
+
+/// Scores the characters needed to complete every incomplete line.
+pub fn get_auto_complete_scores<T>(lines: &[T]) -> Vec<u64>
+where
+    T: AsRef<str>,
+{
+    lines
+        .iter()
+        .map(|l| check_line(l.as_ref()))
+        .map(|e| match e {
+            Some(SyntaxError::MissingCharacters(cs)) => cs.iter().fold(0, |sum, c| {
+                sum * 5
+                    + match c {
+                        ')' => 1,
+                        ']' => 2,
+                        '}' => 3,
+                        '>' => 4,
+                        _ => panic!("invalid character"),
+                    }
+            }),
+            None | Some(SyntaxError::IllegalCharacter(_)) => 0,
+        })
+        .filter(|&s| s > 0)
+        .collect()
+}
+
+/// Returns the median of `scores`, which must have an odd length.
+pub fn median(scores: &[u64]) -> u64 {
+    use std::collections::BinaryHeap;
+
+    let mut heap = scores.iter().collect::<BinaryHeap<_>>();
+    for _ in 0..scores.len() / 2 {
+        heap.pop();
+    }
+
+    return **heap.peek().unwrap();
+}
+
+/// Reason a line fails the syntax check.
+pub enum SyntaxError {
+    /// A closing character that does not match the innermost open chunk.
+    IllegalCharacter(char),
+    /// The closing characters needed to complete the line, innermost first.
+    MissingCharacters(Vec<char>),
+}
+
+/// Checks the chunks of `line` and returns the first error, if any.
+pub fn check_line(line: &str) -> Option<SyntaxError> {
+    let mut stack = Vec::new();
+    for c in line.chars() {
+        match c {
+            '(' => stack.push(')'),
+            '[' => stack.push(']'),
+            '{' => stack.push('}'),
+            '<' => stack.push('>'),
+            ')' | ']' | '}' | '>' if stack.last().map(|&l| c == l).unwrap_or(false) => {
+                stack.pop();
+            }
+            _ => return Some(SyntaxError::IllegalCharacter(c)),
+        }
+    }
+
+    if !stack.is_empty() {
+        stack.reverse();
+        Some(SyntaxError::MissingCharacters(stack))
+    } else {
+        None
+    }
+}
+
+/// Reads the lines of the navigation subsystem from `input`.
+pub fn get_input(input: &Input) -> Result<Vec<String>, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Splits `text` into its lines.
+pub fn parse_input(text: &str) -> Result<Vec<String>, ParseError> {
+    parse_lines(text.trim(), |line| {
+        match line.char_indices().find(|&(_, c)| !"()[]{}<>".contains(c)) {
+            Some((i, c)) => Err(ParseError::at(
+                line,
+                &line[i..i + c.len_utf8()],
+                "invalid character",
+            )),
+            None => Ok(line.to_owned()),
+        }
+    })
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day10>();
+}
+
+#[test]
+fn test_get_error_score() {
+    assert_eq!(
+        get_error_score(&[
+            "[({(<(())[]>[[{[]{<()<>>",
+            "[(()[<>])]({[<{<<[]>>(",
+            "{([(<{}[<>[]}>{[]{[(<()>",
+            "(((({<>}<{<{<>}{[]{[]{}",
+            "[[<[([]))<([[{}[[()]]]",
+            "[{[{({}]{}}([{[{{{}}([]",
+            "{<[[]]>}<{[{[{[]{()[[[]",
+            "[<(<(<(<{}))><([]([]()",
+            "<{([([[(<>()){}]>(<<{{",
+            "<{([{{}}[<[[[<>{}]]]>[]]"
+        ]),
+        26397
+    );
+}
+
+#[test]
+fn test_get_auto_complete_scores() {
+    assert_eq!(
+        get_auto_complete_scores(&[
+            "[({(<(())[]>[[{[]{<()<>>",
+            "[(()[<>])]({[<{<<[]>>(",
+            "{([(<{}[<>[]}>{[]{[(<()>",
+            "(((({<>}<{<{<>}{[]{[]{}",
+            "[[<[([]))<([[{}[[()]]]",
+            "[{[{({}]{}}([{[{{{}}([]",
+            "{<[[]]>}<{[{[{[]{()[[[]",
+            "[<(<(<(<{}))><([]([]()",
+            "<{([([[(<>()){}]>(<<{{",
+            "<{([{{}}[<[[[<>{}]]]>[]]"
+        ]),
+        vec![288957, 5566, 1480781, 995444, 294,]
+    );
+}
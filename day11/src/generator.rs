@@ -0,0 +1,24 @@
+//! Random octopus grids.
+
+use crate::{step, World};
+use grid::Grid;
+use rand::Rng;
+
+/// Steps within which a generated grid has to synchronize.
+const MAX_STEPS: usize = 1000;
+
+/// Generates the energy levels of a `10 * scale` by `10 * scale` grid of octopuses that
+/// all flash at once within [`MAX_STEPS`] steps. Random grids often never synchronize,
+/// so grids that do not are drawn again from a narrower range of energy levels, which
+/// synchronize sooner.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let size = 10 * scale as i32;
+    for levels in (3..=10).rev() {
+        let world: World = Grid::from_fn(size, size, |_, _| rng.gen_range(0..levels));
+        let mut simulated = world.clone();
+        if (0..MAX_STEPS).any(|_| step(&mut simulated) as usize == simulated.len()) {
+            return world.to_string();
+        }
+    }
+    unreachable!("grids of three energy levels synchronize within a few steps");
+}
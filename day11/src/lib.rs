@@ -0,0 +1,117 @@
+//! Day 11: Dumbo Octopus
+
+use common::{Input, ParseError, Solution};
+use grid::Grid;
+use std::collections::HashSet;
+use std::error::Error;
+
+pub mod generator;
+pub mod visualize;
+
+/// Simulates the flashing dumbo octopuses.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = World;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(text: &str) -> Result<World, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(world: &World) -> i32 {
+        simulate_flashes(&mut world.clone(), 100)
+    }
+
+    fn part2(world: &World) -> i32 {
+        simulate_until_synchronized(&mut world.clone())
+    }
+}
+
+/// Runs `n` steps and returns the total number of flashes.
+pub fn simulate_flashes(world: &mut World, n: i32) -> i32 {
+    let mut sum = 0;
+    for _ in 0..n {
+        sum += step(world);
+    }
+    sum
+}
+
+/// Runs steps until all octopuses flash at once and returns that step.
+pub fn simulate_until_synchronized(world: &mut World) -> i32 {
+    let mut steps = 0;
+    loop {
+        steps += 1;
+        let flashes = step(world);
+        if flashes as usize == world.len() {
+            return steps;
+        }
+    }
+}
+
+/// Energy levels of the octopuses in the cavern.
+pub type World = Grid<u32>;
+
+/// Advances the simulation by one step and returns the number of flashes.
+pub fn step(world: &mut World) -> i32 {
+    let mut flashes = 0;
+    let mut has_flashed = HashSet::new();
+    let mut to_flash = Vec::new();
+
+    for v in world.iter_mut() {
+        *v += 1;
+    }
+
+    for (x, y) in world.positions() {
+        if world[(x, y)] > 9 {
+            to_flash.push((x, y));
+        }
+    }
+
+    while let Some((x, y)) = to_flash.pop() {
+        if has_flashed.insert((x, y)) {
+            let neighbours = world.neighbours8(x, y).collect::<Vec<_>>();
+            for (xx, yy) in neighbours {
+                world[(xx, yy)] += 1;
+
+                if world[(xx, yy)] > 9 {
+                    to_flash.push((xx, yy));
+                }
+            }
+
+            flashes += 1;
+        }
+    }
+
+    for v in world.iter_mut() {
+        if *v > 9 {
+            *v = 0;
+        }
+    }
+
+    flashes
+}
+
+/// Reads the octopus grid from `input`.
+pub fn get_input(input: &Input) -> Result<World, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses the octopus grid from `text`.
+pub fn parse_input(text: &str) -> Result<World, ParseError> {
+    Grid::parse_digits(text)
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day11>();
+}
+
+#[test]
+fn test_simulate_steps() {
+    let mut world = parse_input(&common::fixtures::fixture(11, "example")).unwrap();
+    assert_eq!(simulate_flashes(&mut world, 10), 204);
+}
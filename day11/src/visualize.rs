@@ -0,0 +1,58 @@
+//! Animation of the flashing octopuses.
+
+use crate::{parse_input, step, World};
+use common::visualize::{Color, Frame, Simulation};
+use common::ParseError;
+
+/// Octopus grid, advanced until all octopuses flash at once.
+pub struct Octopuses {
+    world: World,
+    steps: usize,
+    flashes: usize,
+    synchronized: bool,
+}
+
+impl Simulation for Octopuses {
+    fn step(&mut self) -> bool {
+        if self.synchronized {
+            return false;
+        }
+        let flashes = step(&mut self.world) as usize;
+        self.steps += 1;
+        self.flashes += flashes;
+        self.synchronized = flashes == self.world.len();
+        true
+    }
+
+    fn render(&self) -> Frame {
+        let mut status = format!("step {}, {} flashes", self.steps, self.flashes);
+        if self.synchronized {
+            status.push_str(", all octopuses flashed at once");
+        }
+
+        let (width, height) = (self.world.width(), self.world.height());
+        let mut frame = Frame::new(status, width as usize, height as usize);
+        for (x, y) in self.world.positions() {
+            let energy = self.world[(x, y)];
+            // Octopuses that just flashed are at zero
+            let color = match energy {
+                0 => Color::Yellow,
+                9 => Color::White,
+                _ => Color::Dim,
+            };
+            let c = char::from_digit(energy, 10).unwrap_or('+');
+            frame.set(x as usize, y as usize, c, color);
+        }
+        frame
+    }
+}
+
+/// Creates the simulation of the octopus grid in `text`.
+pub fn simulation(text: &str) -> Result<Box<dyn Simulation>, ParseError> {
+    Ok(Box::new(Octopuses {
+        world: parse_input(text)?,
+        steps: 0,
+        flashes: 0,
+        synchronized: false,
+    }))
+}
@@ -0,0 +1,45 @@
+//! Random cave systems.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+/// Generates a cave system of `4 + 2 * scale` small and `2 * scale` big caves. Big caves
+/// are never connected to each other, which would allow infinitely many paths, but the
+/// number of paths still grows exponentially with `scale`.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let mut names = HashSet::new();
+    let mut name = |rng: &mut R, letters: &[u8]| loop {
+        let name: String = (0..2)
+            .map(|_| *letters.choose(rng).unwrap() as char)
+            .collect();
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let mut caves = vec!["start".to_owned(), "end".to_owned()];
+    for _ in 0..4 + 2 * scale {
+        caves.push(name(rng, b"abcdefghijklmnopqrstuvwxyz"));
+    }
+    for _ in 0..2 * scale {
+        caves.push(name(rng, b"ABCDEFGHIJKLMNOPQRSTUVWXYZ"));
+    }
+    let is_big = |cave: &str| cave.chars().all(|c| c.is_ascii_uppercase());
+
+    // Connect start and end first, then random pairs of caves
+    let mut pairs = vec![(0, 2), (caves.len() - 1, 1)];
+    let mut connected = HashSet::new();
+    let mut connections = Vec::new();
+    while connections.len() < caves.len() * 5 / 3 {
+        let (a, b) = pairs
+            .pop()
+            .unwrap_or_else(|| (rng.gen_range(0..caves.len()), rng.gen_range(0..caves.len())));
+        let (a, b) = (&caves[a], &caves[b]);
+        if a != b && !(is_big(a) && is_big(b)) && connected.insert((a.min(b), a.max(b))) {
+            connections.push(format!("{}-{}", a, b));
+        }
+    }
+
+    return connections.join("\n") + "\n";
+}
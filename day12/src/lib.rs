@@ -0,0 +1,104 @@
+//! Day 12: Passage Pathing
+
+use common::{parse_lines, Input, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+
+pub mod generator;
+
+/// Counts the paths through the cave system.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = CaveSystem;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(text: &str) -> Result<CaveSystem, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(cave_system: &CaveSystem) -> usize {
+        find_paths(cave_system, false)
+    }
+
+    fn part2(cave_system: &CaveSystem) -> usize {
+        find_paths(cave_system, true)
+    }
+}
+
+/// Adjacency list from each cave to its neighbours.
+pub type CaveSystem = HashMap<String, Vec<String>>;
+
+/// Counts the paths from `start` to `end`, optionally visiting one small cave twice.
+pub fn find_paths(system: &CaveSystem, allow_small_twice: bool) -> usize {
+    let mut paths_found = 0;
+    let mut initial = HashSet::new();
+    initial.insert("start");
+    let mut paths_todo = vec![(false, "start", initial)];
+    let empty = Vec::new();
+
+    while let Some((has_small_twice, current, path)) = paths_todo.pop() {
+        for next in system.get(current).unwrap_or(&empty) {
+            if next == "end" {
+                // Reached the end, increment path counter
+                paths_found += 1;
+            } else if next.chars().next().unwrap().is_ascii_lowercase() {
+                // Next could be a small cave
+                if !path.contains(&next.as_str()) {
+                    // Next has not been visited yet
+                    let mut new_path = path.clone();
+                    new_path.insert(next);
+                    paths_todo.push((has_small_twice, next, new_path));
+                } else if next != "start" && allow_small_twice && !has_small_twice {
+                    // Next has been visited already, but no other small cave was visited twice yet
+                    paths_todo.push((true, next, path.clone()));
+                }
+            } else {
+                // Next is a big cave
+                let mut new_path = path.clone();
+                new_path.insert(next);
+                paths_todo.push((has_small_twice, next, new_path));
+            }
+        }
+    }
+
+    return paths_found;
+}
+
+/// Reads the cave connections from `input`.
+pub fn get_input(input: &Input) -> Result<CaveSystem, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses one `a-b` connection per line into an undirected graph.
+pub fn parse_input(text: &str) -> Result<CaveSystem, ParseError> {
+    let mut map = HashMap::new();
+
+    parse_lines(text.trim(), |line| {
+        if let [from, to] = &line.split("-").collect::<Vec<&str>>()[..] {
+            map.entry(from.to_string())
+                .or_insert_with(Vec::new)
+                .push(to.to_string());
+            map.entry(to.to_string())
+                .or_insert_with(Vec::new)
+                .push(from.to_string());
+            Ok(())
+        } else {
+            Err(ParseError::at(
+                line,
+                line,
+                "expected two caves separated by `-`",
+            ))
+        }
+    })?;
+
+    Ok(map)
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day12>();
+}
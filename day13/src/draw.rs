@@ -0,0 +1,46 @@
+//! Picture of the paper with its fold lines and the code it folds into.
+
+use crate::{execute_fold, parse_input, Dot, Fold};
+use common::svg::{Style, Svg};
+use common::ParseError;
+
+/// Draws the dots of the paper in `text` and the lines it is folded along, with the dots
+/// of the folded paper on top in its upper left corner.
+pub fn drawing(text: &str) -> Result<Svg, ParseError> {
+    let (dots, folds) = parse_input(text)?;
+    let width = dots.iter().map(|&Dot(x, _)| x + 1).max().unwrap_or(1);
+    let height = dots.iter().map(|&Dot(_, y)| y + 1).max().unwrap_or(1);
+    let mut svg = Svg::new(0.0, 0.0, width as f64, height as f64);
+
+    let mut sorted: Vec<_> = dots.iter().collect();
+    sorted.sort();
+    for &Dot(x, y) in sorted {
+        svg.cell(x as f64, y as f64, "#abd9e9");
+    }
+
+    let fold_style = Style::stroke("#d73027", 1.0).dashed();
+    for (i, &fold) in folds.iter().enumerate() {
+        let (from, to, label_at) = match fold {
+            Fold::AlongX(x) => {
+                let x = x as f64 + 0.5;
+                ((x, 0.0), (x, height as f64), (x, height as f64))
+            }
+            Fold::AlongY(y) => {
+                let y = y as f64 + 0.5;
+                ((0.0, y), (width as f64, y), (0.0, y))
+            }
+        };
+        svg.line(from, to, &fold_style);
+        svg.text(label_at.0, label_at.1, &format!("{}", i + 1), "#d73027");
+    }
+
+    let folded = folds
+        .iter()
+        .fold(dots, |dots, &fold| execute_fold(&dots, fold));
+    let mut sorted: Vec<_> = folded.iter().collect();
+    sorted.sort();
+    for &Dot(x, y) in sorted {
+        svg.cell(x as f64, y as f64, "#313695");
+    }
+    return Ok(svg);
+}
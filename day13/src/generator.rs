@@ -0,0 +1,52 @@
+//! Random transparent paper.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generates `900 * scale` dots and twelve folds that fold them onto a random 40 by 6
+/// code. The dots are made by unfolding the code again, mirroring each dot at every fold
+/// with even chance, so no dot ever lies on a fold line.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let (mut width, mut height) = (40, 6);
+    let code: Vec<(i32, i32)> = (0..width)
+        .flat_map(|x| (0..height).map(move |y| (x, y)))
+        .filter(|_| rng.gen_bool(0.5))
+        .collect();
+
+    // Folds in the order they are unfolded, along x if set
+    let mut along_x = vec![true; 5];
+    along_x.extend([false; 7]);
+    along_x.shuffle(rng);
+    let mut folds = Vec::new();
+    for along_x in along_x {
+        if along_x {
+            folds.push((true, width));
+            width = 2 * width + 1;
+        } else {
+            folds.push((false, height));
+            height = 2 * height + 1;
+        }
+    }
+
+    let mut text = String::new();
+    for _ in 0..900 * scale {
+        let (mut x, mut y) = *code.choose(rng).unwrap_or(&(0, 0));
+        for &(along_x, line) in &folds {
+            if rng.gen() {
+                if along_x {
+                    x = 2 * line - x;
+                } else {
+                    y = 2 * line - y;
+                }
+            }
+        }
+        text.push_str(&format!("{},{}\n", x, y));
+    }
+
+    text.push('\n');
+    for &(along_x, line) in folds.iter().rev() {
+        let axis = if along_x { 'x' } else { 'y' };
+        text.push_str(&format!("fold along {}={}\n", axis, line));
+    }
+    return text;
+}
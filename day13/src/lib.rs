@@ -0,0 +1,156 @@
+//! Day 13: Transparent Origami
+
+use common::{parse_lines, sections, Input, ParseError, Parser, Solution};
+use std::collections::{BinaryHeap, HashSet};
+use std::error::Error;
+
+pub mod draw;
+pub mod generator;
+pub mod visualize;
+
+/// Folds the transparent paper and reads the activation code.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = (HashSet<Dot>, Vec<Fold>);
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(text: &str) -> Result<(HashSet<Dot>, Vec<Fold>), ParseError> {
+        parse_input(text)
+    }
+
+    fn part1((dots, folds): &(HashSet<Dot>, Vec<Fold>)) -> usize {
+        execute_fold(dots, folds[0]).len()
+    }
+
+    fn part2((dots, folds): &(HashSet<Dot>, Vec<Fold>)) -> String {
+        let dots = folds
+            .iter()
+            .fold(dots.clone(), |ds, &f| execute_fold(&ds, f));
+        render_dots(&dots)
+    }
+}
+
+/// Position `(x, y)` of a dot on the paper.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Dot(pub i32, pub i32);
+
+impl Ord for Dot {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (other.1, other.0).cmp(&(self.1, self.0))
+    }
+}
+
+impl PartialOrd for Dot {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Fold instruction along a horizontal or vertical line.
+#[derive(Debug, Copy, Clone)]
+pub enum Fold {
+    /// Fold the bottom half up along `y`.
+    AlongY(i32),
+    /// Fold the right half left along `x`.
+    AlongX(i32),
+}
+
+/// Applies `fold` and returns the dots of the folded paper.
+pub fn execute_fold(dots: &HashSet<Dot>, fold: Fold) -> HashSet<Dot> {
+    let mut new_dots = HashSet::new();
+    match fold {
+        Fold::AlongX(fx) => {
+            for &Dot(x, y) in dots {
+                assert_ne!(x, fx);
+                if x < fx {
+                    new_dots.insert(Dot(x, y));
+                } else {
+                    new_dots.insert(Dot(fx + (fx - x), y));
+                }
+            }
+        }
+        Fold::AlongY(fy) => {
+            for &Dot(x, y) in dots {
+                assert_ne!(y, fy);
+                if y < fy {
+                    new_dots.insert(Dot(x, y));
+                } else {
+                    new_dots.insert(Dot(x, fy + (fy - y)));
+                }
+            }
+        }
+    }
+
+    return new_dots;
+}
+
+/// Renders the dots as `#` on a background of `.`.
+pub fn render_dots(dots: &HashSet<Dot>) -> String {
+    let mut sorted: BinaryHeap<_> = dots.iter().collect();
+    let mut output = String::new();
+    let mut last_y = 0;
+    let mut last_x = 0;
+    while let Some(&Dot(x, y)) = sorted.pop() {
+        while y > last_y {
+            output.push('\n');
+            last_y += 1;
+            last_x = 0;
+        }
+        while x > last_x {
+            output.push(' ');
+            last_x += 1;
+        }
+        output.push('#');
+        last_x += 1;
+    }
+    output
+}
+
+/// Reads the dots and fold instructions from `input`.
+pub fn get_input(input: &Input) -> Result<(HashSet<Dot>, Vec<Fold>), Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses the dot coordinates followed by the `fold along` instructions.
+pub fn parse_input(text: &str) -> Result<(HashSet<Dot>, Vec<Fold>), ParseError> {
+    let mut sections = sections(text);
+    let dot_section = sections.next().unwrap_or_default();
+    let dots = parse_lines(dot_section, |line| {
+        let mut parser = Parser::new(line);
+        let (x, y) = parser.separated(",", Parser::number, Parser::number)?;
+        parser.end()?;
+        Ok(Dot(x, y))
+    })
+    .map_err(|e| e.within(text, dot_section))?;
+
+    let fold_section = sections.next().unwrap_or_default();
+    let folds = parse_lines(fold_section, |line| {
+        let mut parser = Parser::new(line);
+        parser.tag("fold along ")?;
+        let axis = parser.one_of(&["x", "y"])?;
+        parser.tag("=")?;
+        let position = parser.number()?;
+        parser.end()?;
+        Ok(if axis == "x" {
+            Fold::AlongX(position)
+        } else {
+            Fold::AlongY(position)
+        })
+    })
+    .map_err(|e| e.within(text, fold_section))?;
+
+    if let Some(section) = sections.next() {
+        return Err(ParseError::at(text, section, "unexpected section"));
+    }
+
+    return Ok((dots.into_iter().collect(), folds));
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day13>();
+}
@@ -0,0 +1,68 @@
+//! Animation of the folding paper.
+
+use crate::{execute_fold, parse_input, Dot, Fold};
+use common::visualize::{Color, Frame, Simulation};
+use common::ParseError;
+use std::collections::HashSet;
+
+/// Transparent paper, folded once per step.
+pub struct Folding {
+    dots: HashSet<Dot>,
+    folds: Vec<Fold>,
+    next: usize,
+}
+
+impl Simulation for Folding {
+    fn step(&mut self) -> bool {
+        match self.folds.get(self.next) {
+            Some(&fold) => {
+                self.dots = execute_fold(&self.dots, fold);
+                self.next += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn render(&self) -> Frame {
+        let mut status = format!(
+            "fold {} of {}, {} dots",
+            self.next,
+            self.folds.len(),
+            self.dots.len()
+        );
+        let next_fold = self.folds.get(self.next).copied();
+        match next_fold {
+            Some(Fold::AlongX(x)) => status.push_str(&format!(", next fold along x={}", x)),
+            Some(Fold::AlongY(y)) => status.push_str(&format!(", next fold along y={}", y)),
+            None => {}
+        }
+
+        let width = self.dots.iter().map(|d| d.0 + 1).max().unwrap_or(0);
+        let height = self.dots.iter().map(|d| d.1 + 1).max().unwrap_or(0);
+        let mut frame = Frame::new(status, width as usize, height as usize);
+
+        // The line of the next fold is drawn below the dots
+        for i in 0..width.max(height) {
+            match next_fold {
+                Some(Fold::AlongX(x)) => frame.set(x as usize, i as usize, '|', Color::Red),
+                Some(Fold::AlongY(y)) => frame.set(i as usize, y as usize, '-', Color::Red),
+                None => {}
+            }
+        }
+        for &Dot(x, y) in &self.dots {
+            frame.set(x as usize, y as usize, '#', Color::Yellow);
+        }
+        frame
+    }
+}
+
+/// Creates the simulation of the dots and fold instructions in `text`.
+pub fn simulation(text: &str) -> Result<Box<dyn Simulation>, ParseError> {
+    let (dots, folds) = parse_input(text)?;
+    Ok(Box::new(Folding {
+        dots,
+        folds,
+        next: 0,
+    }))
+}
@@ -0,0 +1,24 @@
+//! Random polymer templates and insertion rules.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generates a template of `20 * scale` elements and insertion rules for every pair of
+/// the ten elements used.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let mut elements: Vec<char> = ('A'..='Z').collect();
+    elements.shuffle(rng);
+    elements.truncate(10);
+
+    let mut text: String = (0..20 * scale)
+        .map(|_| *elements.choose(rng).unwrap())
+        .collect();
+    text.push_str("\n\n");
+    for &a in &elements {
+        for &b in &elements {
+            let c = *elements.choose(rng).unwrap();
+            text.push_str(&format!("{}{} -> {}\n", a, b, c));
+        }
+    }
+    return text;
+}
@@ -0,0 +1,191 @@
+//! Day 14: Extended Polymerization
+
+use common::{parse_lines, sections, Input, ParseError, Parser, Solution};
+use std::collections::HashMap;
+use std::error::Error;
+
+pub mod generator;
+
+/// Grows the polymer by pair insertion.
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = (String, ElementMap);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(text: &str) -> Result<(String, ElementMap), ParseError> {
+        parse_input(text)
+    }
+
+    fn part1((template, element_map): &(String, ElementMap)) -> u64 {
+        count_difference(template, element_map, 10)
+    }
+
+    fn part2((template, element_map): &(String, ElementMap)) -> u64 {
+        count_difference_fast(template, element_map, 40)
+    }
+}
+
+/// Builds the polymer for `steps` steps and subtracts the least from the most common element count.
+pub fn count_difference(template: &str, element_map: &ElementMap, steps: usize) -> u64 {
+    // work with slower algorithm
+    let mut step: Vec<char> = template.chars().collect();
+    for _ in 0..steps {
+        step = perform_insertions(element_map, &step);
+    }
+    let (most_common, least_common) = count_elements(&step);
+    most_common - least_common
+}
+
+/// Like [`count_difference`], but only tracks the counts of element pairs.
+pub fn count_difference_fast(template: &str, element_map: &ElementMap, steps: usize) -> u64 {
+    // work with faster algorithm, avoiding memory exhaustion
+    let mut pairs = count_pairs(&template.chars().collect::<Vec<_>>());
+    for _ in 0..steps {
+        pairs = perform_insertions_fast(element_map, &pairs);
+    }
+    let (most_common, least_common) = count_elements_from_pairs(&pairs);
+    most_common - least_common
+}
+
+/// Pair insertion rules from an element pair to the inserted element.
+pub type ElementMap = HashMap<(char, char), char>;
+/// Element pairs of a polymer together with how often they occur.
+pub type ElementPairs = Vec<(char, char, u64)>;
+
+/// Applies one step of pair insertion to the polymer `input`.
+pub fn perform_insertions(element_map: &ElementMap, input: &[char]) -> Vec<char> {
+    let mut result = Vec::new();
+    for i in 0..input.len() - 1 {
+        let a = input[i];
+        let b = input[i + 1];
+        result.push(a);
+        result.push(*element_map.get(&(a, b)).unwrap());
+    }
+    result.push(*input.last().unwrap());
+
+    assert_eq!(result.len(), input.len() * 2 - 1);
+
+    result
+}
+
+/// Applies one step of pair insertion to the pair counts `input`.
+pub fn perform_insertions_fast(
+    element_map: &ElementMap,
+    input_pairs: &ElementPairs,
+) -> ElementPairs {
+    let mut result = HashMap::new();
+    for &(a, b, count) in input_pairs {
+        let &c = element_map.get(&(a, b)).unwrap();
+        *result.entry((a, c)).or_insert(0) += count;
+        *result.entry((c, b)).or_insert(0) += count;
+    }
+
+    result
+        .iter()
+        .map(|(&(a, b), &count)| (a, b, count))
+        .collect()
+}
+
+/// Counts the adjacent element pairs of the polymer `input`.
+pub fn count_pairs(input: &[char]) -> ElementPairs {
+    let mut result = HashMap::new();
+    for i in 0..input.len() - 1 {
+        let a = input[i];
+        let b = input[i + 1];
+        *result.entry((a, b)).or_insert(0) += 1;
+    }
+
+    result
+        .iter()
+        .map(|(&(a, b), &count)| (a, b, count))
+        .collect()
+}
+
+/// Returns the counts of the most and least common elements.
+pub fn count_elements(input: &[char]) -> (u64, u64) {
+    let mut counts = vec![0; 26];
+    for &c in input {
+        counts[c as usize - 'A' as usize] += 1;
+    }
+
+    return (
+        *counts.iter().max().unwrap(),
+        *counts.iter().filter(|&&c| c > 0).min().unwrap(),
+    );
+}
+
+/// Returns the counts of the most and least common elements from pair counts.
+pub fn count_elements_from_pairs(input: &ElementPairs) -> (u64, u64) {
+    let mut counts = vec![0; 26];
+    for &(a, b, count) in input {
+        counts[a as usize - 'A' as usize] += count;
+        counts[b as usize - 'A' as usize] += count;
+    }
+
+    for count in &mut counts {
+        // if count is uneven, it is either the first or last letter in original input
+        if *count % 2 == 1 {
+            *count += 1;
+        }
+        *count /= 2;
+    }
+
+    return (
+        *counts.iter().max().unwrap(),
+        *counts.iter().filter(|&&c| c > 0).min().unwrap(),
+    );
+}
+
+/// Reads the polymer template and insertion rules from `input`.
+pub fn get_input(input: &Input) -> Result<(String, ElementMap), Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses the template line followed by `AB -> C` rules.
+pub fn parse_input(text: &str) -> Result<(String, ElementMap), ParseError> {
+    fn element(parser: &mut Parser) -> Result<char, ParseError> {
+        parser.char_where(|c| c.is_ascii_uppercase(), "an element")
+    }
+
+    let mut sections = sections(text);
+    let mut parser = Parser::new(sections.next().unwrap_or_default());
+    let template = parser.take_while(|c| c.is_ascii_uppercase());
+    parser.end()?;
+
+    let rule_section = sections.next().unwrap_or_default();
+    let rules = parse_lines(rule_section, |line| {
+        let mut parser = Parser::new(line);
+        let (pair, inserted) = parser.separated(
+            " -> ",
+            |parser| Ok((element(parser)?, element(parser)?)),
+            element,
+        )?;
+        parser.end()?;
+        Ok((pair, inserted))
+    })
+    .map_err(|e| e.within(text, rule_section))?;
+
+    return Ok((template.to_string(), rules.into_iter().collect()));
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day14>();
+}
+
+#[test]
+fn test_slow_and_fast() {
+    let (input, element_map) = get_input(&Input::default_for(14)).unwrap();
+    let mut step: Vec<char> = input.chars().collect();
+    let mut pairs = count_pairs(&input.chars().collect::<Vec<_>>());
+    for _ in 0..10 {
+        step = perform_insertions(&element_map, &step);
+        pairs = perform_insertions_fast(&element_map, &pairs);
+        // Both implementations should come to the same result
+        assert_eq!(count_elements(&step), count_elements_from_pairs(&pairs));
+    }
+}
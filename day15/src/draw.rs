@@ -0,0 +1,31 @@
+//! Picture of the risk levels of the cave and the path with the lowest total risk.
+
+use crate::{find_path, parse_input};
+use common::svg::{heat, Style, Svg};
+use common::ParseError;
+
+/// Draws the risk levels of the cave in `text` from blue for 1 to red for 9, and the
+/// path with the lowest total risk through it.
+pub fn drawing(text: &str) -> Result<Svg, ParseError> {
+    let map = parse_input(text)?;
+    let mut svg = Svg::new(0.0, 0.0, map.width() as f64, map.height() as f64);
+    for (x, y) in map.positions() {
+        let risk = map[(x, y)];
+        svg.cell(x as f64, y as f64, &heat((risk - 1) as f64 / 8.0));
+    }
+
+    let goal = (map.width() - 1, map.height() - 1);
+    let (total, path) = find_path(&map, goal).unwrap_or((0, vec![(0, 0)]));
+    let points: Vec<_> = path
+        .iter()
+        .map(|&(x, y)| (x as f64 + 0.5, y as f64 + 0.5))
+        .collect();
+    svg.polyline(&points, &Style::stroke("black", 2.0));
+    svg.text(
+        4.0 * svg.pixel(),
+        map.height() as f64 - 4.0 * svg.pixel(),
+        &format!("lowest total risk {}", total),
+        "black",
+    );
+    return Ok(svg);
+}
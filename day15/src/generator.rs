@@ -0,0 +1,12 @@
+//! Random risk level maps.
+
+use crate::Map;
+use grid::Grid;
+use rand::Rng;
+
+/// Generates the risk levels of a `100 * scale` by `100 * scale` cave.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let size = 100 * scale as i32;
+    let map: Map = Grid::from_fn(size, size, |_, _| rng.gen_range(1..10));
+    return map.to_string();
+}
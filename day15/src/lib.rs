@@ -0,0 +1,149 @@
+//! Day 15: Chiton
+
+use common::{Input, ParseError, Solution};
+use grid::Grid;
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+
+pub mod draw;
+pub mod generator;
+
+/// Finds the path with the lowest total risk through the cave.
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Map;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(text: &str) -> Result<Map, ParseError> {
+        let map = parse_input(text)?;
+        // Any non-empty map has a path, as every position is reachable
+        if map.width() == 0 {
+            return Err(ParseError::at_end(text, "expected a map of risk levels"));
+        }
+        Ok(map)
+    }
+
+    fn part1(map: &Map) -> i32 {
+        let goal = (map.width() - 1, map.height() - 1);
+        let (risk, _) = find_path(map, goal).expect("failed to find path");
+        risk
+    }
+
+    fn part2(map: &Map) -> i32 {
+        let expanded_map = expand(map, 5);
+        let goal = (expanded_map.width() - 1, expanded_map.height() - 1);
+        let (risk, _) = find_path(&expanded_map, goal).expect("failed to find path");
+        risk
+    }
+}
+
+/// Returns the lowest total risk of a path from the top left to `goal`, and the positions
+/// along that path.
+pub fn find_path(map: &Map, goal: (i32, i32)) -> Option<(i32, Vec<(i32, i32)>)> {
+    // A* algorithm for finding the optimal path
+    let mut open_list = PriorityQueue::new();
+    let mut closed_list = HashSet::new();
+    let mut came_from = HashMap::new();
+    let mut previous = HashMap::new();
+    let (gx, gy) = goal;
+
+    open_list.push((0, 0), Reverse(0));
+    came_from.insert((0, 0), 0);
+
+    while let Some(((x, y), _)) = open_list.pop() {
+        if (x, y) == goal {
+            let mut path = vec![goal];
+            while let Some(&position) = previous.get(path.last().unwrap()) {
+                path.push(position);
+            }
+            path.reverse();
+            return Some((*came_from.get(&goal).unwrap(), path));
+        }
+        let &cost = came_from.get(&(x, y)).unwrap();
+
+        closed_list.insert((x, y));
+
+        for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let xx = x + dx;
+            let yy = y + dy;
+            if !closed_list.contains(&(xx, yy)) {
+                if let Some(&risk) = map.get(xx, yy) {
+                    // Calculate new cost with heuristic for remaining path to goal
+                    let new_cost = cost + risk + i32::abs(gx - xx) + i32::abs(gy - yy);
+                    if let Some(&Reverse(old_cost)) = open_list.get_priority(&(xx, yy)) {
+                        if old_cost > new_cost {
+                            open_list.change_priority(&(xx, yy), Reverse(new_cost));
+                            *came_from.get_mut(&(xx, yy)).unwrap() = cost + risk;
+                            previous.insert((xx, yy), (x, y));
+                        }
+                    } else {
+                        open_list.push((xx, yy), Reverse(new_cost));
+                        came_from.insert((xx, yy), cost + risk);
+                        previous.insert((xx, yy), (x, y));
+                    }
+                }
+            }
+        }
+    }
+
+    return None;
+}
+
+/// Risk levels of the cave.
+pub type Map = Grid<i32>;
+
+/// Tiles the map `factor` times in both directions, increasing the risk of each tile.
+pub fn expand(map: &Map, factor: i32) -> Map {
+    Grid::from_fn(map.width() * factor, map.height() * factor, |x, y| {
+        let increase = x / map.width() + y / map.height();
+        (map[(x % map.width(), y % map.height())] - 1 + increase) % 9 + 1
+    })
+}
+
+/// Reads the risk levels from `input`.
+pub fn get_input(input: &Input) -> Result<Map, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses the risk levels from `text`.
+pub fn parse_input(text: &str) -> Result<Map, ParseError> {
+    Grid::parse_digits(text)
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day15>();
+}
+
+#[test]
+fn test_find_path() {
+    let map = parse_input(&common::fixtures::fixture(15, "example")).unwrap();
+    let goal = (map.width() - 1, map.height() - 1);
+    let (risk, path) = find_path(&map, goal).unwrap();
+    assert_eq!(risk, 40);
+    assert_eq!((path[0], path[path.len() - 1]), ((0, 0), goal));
+    assert_eq!(path.iter().skip(1).map(|&p| map[p]).sum::<i32>(), risk);
+}
+
+#[test]
+fn test_empty_map() {
+    let err = Day15::parse("\n").unwrap_err();
+    assert_eq!(err.message, "expected a map of risk levels");
+}
+
+#[test]
+fn test_find_path_expanded() {
+    let map = expand(
+        &parse_input(&common::fixtures::fixture(15, "example")).unwrap(),
+        5,
+    );
+    let goal = (map.width() - 1, map.height() - 1);
+    let result = find_path(&map, goal).map(|(risk, _)| risk);
+    assert_eq!(result, Some(315));
+}
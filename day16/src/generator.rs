@@ -0,0 +1,84 @@
+//! Random BITS transmissions.
+
+use crate::{bits_to_hex, write_bits, write_literal};
+use rand::seq::index;
+use rand::Rng;
+
+/// Largest value of a generated expression, well within a `u64`.
+const MAX_VALUE: u64 = 1 << 48;
+
+/// Generates a transmission of a single packet containing `250 * scale` packets in
+/// total. Operands of sums and products are kept small enough for no value to exceed
+/// [`MAX_VALUE`].
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let mut bits = String::new();
+    write_packet(rng, 250 * scale, MAX_VALUE, &mut bits);
+
+    let mut text = bits_to_hex(&bits);
+    text.push('\n');
+    return text;
+}
+
+/// Appends the bits of a packet made of `count` packets whose value is at most `limit`
+/// and returns that value.
+fn write_packet<R: Rng>(rng: &mut R, count: usize, limit: u64, bits: &mut String) -> u64 {
+    write_bits(bits, rng.gen_range(0..8), 3);
+
+    if count == 1 {
+        let magnitude = rng.gen_range(1..=40);
+        let value = rng.gen_range(0..=limit.min((1 << magnitude) - 1));
+        write_bits(bits, 4, 3);
+        write_literal(bits, value);
+        return value;
+    }
+
+    let type_ = if count == 2 || limit == 0 {
+        rng.gen_range(0..4)
+    } else {
+        [0, 1, 2, 3, 5, 6, 7][rng.gen_range(0..7)]
+    };
+    let operands = if type_ >= 5 {
+        2
+    } else {
+        rng.gen_range(1..=(count - 1).min(5))
+    };
+    let operand_limit = match type_ {
+        0 => limit / operands as u64,
+        1 => (limit as f64).powf(1.0 / operands as f64) as u64,
+        2 | 3 => limit,
+        _ => MAX_VALUE,
+    };
+
+    // Split the remaining packets into non-empty parts, one per operand
+    let mut cuts = index::sample(rng, count - 2, operands - 1).into_vec();
+    cuts.iter_mut().for_each(|cut| *cut += 1);
+    cuts.push(0);
+    cuts.push(count - 1);
+    cuts.sort_unstable();
+
+    let mut sub_packets = String::new();
+    let values: Vec<u64> = cuts
+        .windows(2)
+        .map(|w| write_packet(rng, w[1] - w[0], operand_limit, &mut sub_packets))
+        .collect();
+
+    write_bits(bits, type_, 3);
+    if sub_packets.len() < 1 << 15 && rng.gen() {
+        bits.push('0');
+        write_bits(bits, sub_packets.len() as u64, 15);
+    } else {
+        bits.push('1');
+        write_bits(bits, operands as u64, 11);
+    }
+    bits.push_str(&sub_packets);
+
+    return match type_ {
+        0 => values.iter().sum(),
+        1 => values.iter().product(),
+        2 => *values.iter().min().unwrap(),
+        3 => *values.iter().max().unwrap(),
+        5 => (values[0] > values[1]) as u64,
+        6 => (values[0] < values[1]) as u64,
+        _ => (values[0] == values[1]) as u64,
+    };
+}
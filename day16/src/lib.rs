@@ -0,0 +1,382 @@
+//! Day 16: Packet Decoder
+
+use common::{Input, ParseError, Solution};
+use std::error::Error;
+use std::fmt;
+
+pub mod generator;
+
+/// Decodes the BITS transmission.
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Packet;
+    type Part1 = i32;
+    type Part2 = u64;
+
+    fn parse(text: &str) -> Result<Packet, ParseError> {
+        let packet = parse_packet(&mut parse_input(text)?)?;
+        if evaluate_packet(&packet).is_none() {
+            let message = "value of the transmission does not fit into 64 bits";
+            return Err(ParseError::at(text, text.trim(), message));
+        }
+        Ok(packet)
+    }
+
+    fn part1(packet: &Packet) -> i32 {
+        sum_versions(packet)
+    }
+
+    fn part2(packet: &Packet) -> u64 {
+        evaluate_packet(packet).expect("value is checked when parsing")
+    }
+}
+
+/// Bit reader over a hexadecimal transmission.
+pub struct Reader {
+    hex_string: String,
+    blocks: Vec<u8>,
+    pos: usize,
+}
+
+impl Reader {
+    /// Creates a reader positioned at the first bit of `hex_string`.
+    pub fn new(hex_string: &str) -> Result<Self, ParseError> {
+        let blocks = hex_string
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(16).map(|d| d as u8).ok_or_else(|| {
+                    let digit = &hex_string[i..i + c.len_utf8()];
+                    ParseError::at(hex_string, digit, "invalid hexadecimal digit")
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Reader {
+            hex_string: hex_string.to_owned(),
+            blocks,
+            pos: 0,
+        })
+    }
+
+    /// Creates an error about the hexadecimal digit currently being read.
+    pub fn error(&self, message: &str) -> ParseError {
+        let block = (self.pos.max(1) - 1) / 4;
+        match self.hex_string.get(block..block + 1) {
+            Some(digit) => ParseError::at(&self.hex_string, digit, message),
+            None => ParseError::at_end(&self.hex_string, message),
+        }
+    }
+
+    /// Reads a single bit.
+    pub fn consume(&mut self) -> Result<bool, ParseError> {
+        let block = self.pos / 4;
+        let shift = 3 - self.pos % 4;
+        if block < self.blocks.len() {
+            self.pos += 1;
+            return Ok(((self.blocks[block] >> shift) & 1) == 1);
+        }
+
+        Err(ParseError::at_end(
+            &self.hex_string,
+            "unexpected end of transmission",
+        ))
+    }
+
+    /// Reads `length` bits, at most 8, as a number.
+    pub fn read_u8(&mut self, length: u8) -> Result<u8, ParseError> {
+        let mut result = 0;
+        for _ in 0..length {
+            result <<= 1;
+            result |= if self.consume()? { 1 } else { 0 };
+        }
+        return Ok(result);
+    }
+
+    /// Reads `length` bits, at most 64, as a number.
+    pub fn read_u64(&mut self, length: u8) -> Result<u64, ParseError> {
+        let mut result = 0;
+        for _ in 0..length {
+            result <<= 1;
+            result |= if self.consume()? { 1 } else { 0 };
+        }
+        return Ok(result);
+    }
+}
+
+impl fmt::Display for Reader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for b in &self.blocks {
+            write!(f, "{}{}{}{} ", b >> 3 & 1, b >> 2 & 1, b >> 1 & 1, b & 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Packet with its version and contents.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Packet(pub u8, pub PacketData);
+
+/// Contents of a packet by type ID.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PacketData {
+    Literal(u64),
+    Sum(Vec<Packet>),
+    Product(Vec<Packet>),
+    Minimum(Vec<Packet>),
+    Maximum(Vec<Packet>),
+    GreaterThan(Box<Packet>, Box<Packet>),
+    LessThan(Box<Packet>, Box<Packet>),
+    EqualTo(Box<Packet>, Box<Packet>),
+}
+
+/// Reads a single packet including its sub-packets.
+pub fn parse_packet(reader: &mut Reader) -> Result<Packet, ParseError> {
+    fn parse_operands(reader: &mut Reader) -> Result<(Box<Packet>, Box<Packet>), ParseError> {
+        let mut packets = parse_packet_list(reader)?;
+        if packets.len() != 2 {
+            return Err(reader.error("comparison needs exactly two sub-packets"));
+        }
+        let b = packets.pop().unwrap();
+        let a = packets.pop().unwrap();
+        Ok((Box::new(a), Box::new(b)))
+    }
+
+    let version = reader.read_u8(3)?;
+    let type_ = reader.read_u8(3)?;
+
+    let data = match type_ {
+        0 => PacketData::Sum(parse_packet_list(reader)?),
+        1 => PacketData::Product(parse_packet_list(reader)?),
+        2 => PacketData::Minimum(parse_packet_list(reader)?),
+        3 => PacketData::Maximum(parse_packet_list(reader)?),
+        4 => {
+            let mut value: u64 = 0;
+            let mut last_done = false;
+            while !last_done {
+                last_done = !reader.consume()?; // check first bit of group
+                let block = reader.read_u8(4)?;
+                if value >> 60 != 0 {
+                    return Err(reader.error("literal does not fit into 64 bits"));
+                }
+                value = value << 4 | block as u64;
+            }
+
+            PacketData::Literal(value)
+        }
+        5 => {
+            let (a, b) = parse_operands(reader)?;
+            PacketData::GreaterThan(a, b)
+        }
+        6 => {
+            let (a, b) = parse_operands(reader)?;
+            PacketData::LessThan(a, b)
+        }
+        7 => {
+            let (a, b) = parse_operands(reader)?;
+            PacketData::EqualTo(a, b)
+        }
+        _ => {
+            return Err(reader.error(&format!("invalid packet type {}", type_)));
+        }
+    };
+
+    Ok(Packet(version, data))
+}
+
+/// Reads the sub-packets of an operator packet, of which there is at least one.
+pub fn parse_packet_list(reader: &mut Reader) -> Result<Vec<Packet>, ParseError> {
+    let length_type = reader.consume()?;
+    let packets = if length_type {
+        // read 11 bit as number of sub packets
+        let count = reader.read_u64(11)?;
+        (0..count)
+            .map(|_| parse_packet(reader))
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        // read 15 bit as length of sub packets
+        let length = reader.read_u64(15)? as usize;
+        let start = reader.pos;
+        let mut packets = Vec::new();
+        while reader.pos - start < length {
+            packets.push(parse_packet(reader)?);
+        }
+        if reader.pos - start != length {
+            return Err(reader.error("sub-packets run past their declared length"));
+        }
+        packets
+    };
+
+    if packets.is_empty() {
+        return Err(reader.error("operator packet has no sub-packets"));
+    }
+    Ok(packets)
+}
+
+/// Reason a packet cannot be encoded as a transmission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// The version does not fit into its 3 bits.
+    InvalidVersion(u8),
+    /// An operator packet has no sub-packets, which the parser would reject.
+    NoSubPackets,
+    /// The sub-packets are longer than the 15 bit length field allows and more than the
+    /// 11 bit count field allows.
+    TooManySubPackets(usize),
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EncodeError::InvalidVersion(version) => {
+                write!(f, "version {} does not fit into 3 bits", version)
+            }
+            EncodeError::NoSubPackets => write!(f, "operator packet has no sub-packets"),
+            EncodeError::TooManySubPackets(count) => {
+                write!(f, "{} sub-packets do not fit into 11 bits", count)
+            }
+        }
+    }
+}
+
+impl Error for EncodeError {}
+
+/// Encodes the packet as a hexadecimal transmission, the inverse of [`parse_packet`].
+pub fn encode_packet(packet: &Packet) -> Result<String, EncodeError> {
+    let mut bits = String::new();
+    write_packet(packet, &mut bits)?;
+    return Ok(bits_to_hex(&bits));
+}
+
+fn write_packet(Packet(version, data): &Packet, bits: &mut String) -> Result<(), EncodeError> {
+    if *version >= 1 << 3 {
+        return Err(EncodeError::InvalidVersion(*version));
+    }
+    let (type_, sub_packets) = match data {
+        PacketData::Literal(value) => {
+            write_bits(bits, *version as u64, 3);
+            write_bits(bits, 4, 3);
+            write_literal(bits, *value);
+            return Ok(());
+        }
+        PacketData::Sum(ps) => (0, ps.iter().collect()),
+        PacketData::Product(ps) => (1, ps.iter().collect()),
+        PacketData::Minimum(ps) => (2, ps.iter().collect()),
+        PacketData::Maximum(ps) => (3, ps.iter().collect()),
+        PacketData::GreaterThan(a, b) => (5, vec![&**a, &**b]),
+        PacketData::LessThan(a, b) => (6, vec![&**a, &**b]),
+        PacketData::EqualTo(a, b) => (7, vec![&**a, &**b]),
+    };
+
+    if sub_packets.is_empty() {
+        return Err(EncodeError::NoSubPackets);
+    }
+    let mut sub_bits = String::new();
+    for packet in &sub_packets {
+        write_packet(packet, &mut sub_bits)?;
+    }
+
+    write_bits(bits, *version as u64, 3);
+    write_bits(bits, type_, 3);
+    if sub_bits.len() < 1 << 15 {
+        bits.push('0');
+        write_bits(bits, sub_bits.len() as u64, 15);
+    } else if sub_packets.len() < 1 << 11 {
+        bits.push('1');
+        write_bits(bits, sub_packets.len() as u64, 11);
+    } else {
+        return Err(EncodeError::TooManySubPackets(sub_packets.len()));
+    }
+    bits.push_str(&sub_bits);
+    Ok(())
+}
+
+/// Appends the lowest `length` bits of `value`.
+pub(crate) fn write_bits(bits: &mut String, value: u64, length: u32) {
+    for i in (0..length).rev() {
+        bits.push(if value >> i & 1 == 1 { '1' } else { '0' });
+    }
+}
+
+/// Appends the groups of a literal value, as few as are needed to represent it.
+pub(crate) fn write_literal(bits: &mut String, value: u64) {
+    let groups = (0..16)
+        .rev()
+        .filter(|&g| value >> (4 * g) > 0)
+        .max()
+        .unwrap_or(0);
+    for g in (0..=groups).rev() {
+        bits.push(if g > 0 { '1' } else { '0' });
+        write_bits(bits, value >> (4 * g) & 0xf, 4);
+    }
+}
+
+/// Pads `bits` with zeros to whole hexadecimal digits and converts them.
+pub(crate) fn bits_to_hex(bits: &str) -> String {
+    let mut bits = bits.to_owned();
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+
+    return bits
+        .as_bytes()
+        .chunks(4)
+        .map(|digit| {
+            let digit = std::str::from_utf8(digit).unwrap();
+            let digit = u32::from_str_radix(digit, 2).unwrap();
+            char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+}
+
+/// Sums the versions of the packet and all of its sub-packets.
+pub fn sum_versions(Packet(version, data): &Packet) -> i32 {
+    match data {
+        PacketData::Literal(_) => *version as i32,
+        PacketData::Sum(ps)
+        | PacketData::Product(ps)
+        | PacketData::Minimum(ps)
+        | PacketData::Maximum(ps) => *version as i32 + ps.iter().map(sum_versions).sum::<i32>(),
+        PacketData::GreaterThan(a, b) | PacketData::LessThan(a, b) | PacketData::EqualTo(a, b) => {
+            *version as i32 + sum_versions(a) + sum_versions(b)
+        }
+    }
+}
+
+/// Evaluates the expression the packet represents, or returns `None` if a value does not
+/// fit into 64 bits or an operator has no sub-packets.
+pub fn evaluate_packet(Packet(_, data): &Packet) -> Option<u64> {
+    let values = |ps: &[Packet]| ps.iter().map(evaluate_packet).collect::<Option<Vec<_>>>();
+    let value = match data {
+        PacketData::Literal(value) => *value,
+        PacketData::Sum(ps) => ps
+            .iter()
+            .try_fold(0u64, |sum, p| sum.checked_add(evaluate_packet(p)?))?,
+        PacketData::Product(ps) => ps
+            .iter()
+            .try_fold(1u64, |product, p| product.checked_mul(evaluate_packet(p)?))?,
+        PacketData::Minimum(ps) => values(ps)?.into_iter().min()?,
+        PacketData::Maximum(ps) => values(ps)?.into_iter().max()?,
+        PacketData::GreaterThan(a, b) => (evaluate_packet(a)? > evaluate_packet(b)?) as u64,
+        PacketData::LessThan(a, b) => (evaluate_packet(a)? < evaluate_packet(b)?) as u64,
+        PacketData::EqualTo(a, b) => (evaluate_packet(a)? == evaluate_packet(b)?) as u64,
+    };
+    Some(value)
+}
+
+/// Reads the transmission from `input`.
+pub fn get_input(input: &Input) -> Result<Reader, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Creates a reader over the hexadecimal transmission in `text`.
+pub fn parse_input(text: &str) -> Result<Reader, ParseError> {
+    Reader::new(text.trim())
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day16>();
+}
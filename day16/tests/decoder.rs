@@ -0,0 +1,153 @@
+use common::Solution;
+use day16::{
+    encode_packet, evaluate_packet, parse_packet, sum_versions, Day16, EncodeError, Packet,
+    PacketData, Reader,
+};
+use proptest::prelude::*;
+
+#[test]
+fn test_literal() {
+    let packet = parse_packet(&mut Reader::new("D2FE28").unwrap()).unwrap();
+    assert_eq!(packet.0, 6);
+    assert_eq!(packet.1, PacketData::Literal(2021));
+}
+
+#[test]
+fn test_sum_versions() {
+    let examples = [
+        ("8A004A801A8002F478", 16),
+        ("620080001611562C8802118E34", 12),
+        ("C0015000016115A2E0802F182340", 23),
+        ("A0016C880162017C3686B18A3D4780", 31),
+    ];
+    for (hex, expected) in examples {
+        assert_eq!(
+            sum_versions(&parse_packet(&mut Reader::new(hex).unwrap()).unwrap()),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_evaluate_packet() {
+    let examples = [
+        ("C200B40A82", 3),
+        ("04005AC33890", 54),
+        ("880086C3E88112", 7),
+        ("CE00C43D881120", 9),
+        ("D8005AC2A8F0", 1),
+        ("F600BC2D8F", 0),
+        ("9C005AC2F8F0", 0),
+        ("9C0141080250320F1802104A08", 1),
+    ];
+    for (hex, expected) in examples {
+        assert_eq!(
+            evaluate_packet(&parse_packet(&mut Reader::new(hex).unwrap()).unwrap()),
+            Some(expected)
+        );
+    }
+}
+
+#[test]
+fn test_truncated() {
+    let err = parse_packet(&mut Reader::new("D2FE").unwrap()).unwrap_err();
+    assert_eq!((err.line, err.column), (1, 5));
+    assert!(Reader::new("D2FX").is_err());
+}
+
+#[test]
+fn test_declared_length() {
+    // The example with two sub-packets of 27 bits in total, declared as 26 bits
+    let err = parse_packet(&mut Reader::new("38006B45291200").unwrap()).unwrap_err();
+    assert_eq!(err.message, "sub-packets run past their declared length");
+    assert!(parse_packet(&mut Reader::new("38006F45291200").unwrap()).is_ok());
+}
+
+#[test]
+fn test_literal_overflow() {
+    // A literal of 17 groups, one more than fits into 64 bits
+    let err = parse_packet(&mut Reader::new("13FFFFFFFFFFFFFFFFFFFDE").unwrap()).unwrap_err();
+    assert_eq!(err.message, "literal does not fit into 64 bits");
+}
+
+#[test]
+fn test_encode_packet() {
+    let packet = Packet(6, PacketData::Literal(2021));
+    assert_eq!(encode_packet(&packet), Ok("D2FE28".to_owned()));
+}
+
+#[test]
+fn test_encode_bounds() {
+    let literals = |count| vec![Packet(0, PacketData::Literal(u64::MAX)); count];
+
+    // Too long for the 15 bit length, so the 11 bit count is used
+    let packet = Packet(0, PacketData::Sum(literals(2047)));
+    let hex = encode_packet(&packet).unwrap();
+    assert_eq!(parse_packet(&mut Reader::new(&hex).unwrap()), Ok(packet));
+
+    let packet = Packet(0, PacketData::Sum(literals(2048)));
+    assert_eq!(
+        encode_packet(&packet),
+        Err(EncodeError::TooManySubPackets(2048))
+    );
+
+    let packet = Packet(8, PacketData::Literal(1));
+    assert_eq!(encode_packet(&packet), Err(EncodeError::InvalidVersion(8)));
+
+    let packet = Packet(0, PacketData::Sum(vec![]));
+    assert_eq!(encode_packet(&packet), Err(EncodeError::NoSubPackets));
+}
+
+#[test]
+fn test_evaluate_overflow() {
+    let max = Packet(0, PacketData::Literal(u64::MAX));
+    let sum = Packet(0, PacketData::Sum(vec![max.clone(), max.clone()]));
+    assert_eq!(evaluate_packet(&sum), None);
+    let product = Packet(0, PacketData::Product(vec![max.clone(), max.clone()]));
+    assert_eq!(evaluate_packet(&product), None);
+    let minimum = Packet(0, PacketData::Minimum(vec![max, sum]));
+    assert_eq!(evaluate_packet(&minimum), None);
+
+    let hex = encode_packet(&product).unwrap();
+    let err = Day16::parse(&hex).unwrap_err();
+    assert_eq!(
+        err.message,
+        "value of the transmission does not fit into 64 bits"
+    );
+}
+
+/// Strategy generating packets nested a few levels deep.
+fn arbitrary_packet() -> impl Strategy<Value = Packet> {
+    let literal =
+        (0..8u8, any::<u64>()).prop_map(|(v, value)| Packet(v, PacketData::Literal(value)));
+    literal.prop_recursive(4, 32, 4, |inner| {
+        let list = prop::collection::vec(inner.clone(), 1..4);
+        let pair = (inner.clone(), inner).prop_map(|(a, b)| (Box::new(a), Box::new(b)));
+        let data = prop_oneof![
+            list.clone().prop_map(PacketData::Sum),
+            list.clone().prop_map(PacketData::Product),
+            list.clone().prop_map(PacketData::Minimum),
+            list.prop_map(PacketData::Maximum),
+            pair.clone()
+                .prop_map(|(a, b)| PacketData::GreaterThan(a, b)),
+            pair.clone().prop_map(|(a, b)| PacketData::LessThan(a, b)),
+            pair.prop_map(|(a, b)| PacketData::EqualTo(a, b)),
+        ];
+        (0..8u8, data).prop_map(|(v, data)| Packet(v, data))
+    })
+}
+
+proptest! {
+    #[test]
+    fn test_encode_round_trip(packet in arbitrary_packet()) {
+        let hex = encode_packet(&packet).unwrap();
+        prop_assert_eq!(parse_packet(&mut Reader::new(&hex).unwrap()), Ok(packet));
+    }
+
+    #[test]
+    fn test_parse_never_panics(text in "[0-9A-F]{0,64}|\\PC{0,16}") {
+        if let Ok(mut reader) = Reader::new(&text) {
+            let _ = parse_packet(&mut reader);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Picture of the highest shot hitting the target area.
+
+use crate::{highest_shot, parse_input, Pos, Velocity};
+use common::svg::{Style, Svg};
+use common::ParseError;
+
+/// Draws the target area in `text` and the trajectory of the shot reaching the highest
+/// `y` while hitting it, with `y` growing upwards.
+pub fn drawing(text: &str) -> Result<Svg, ParseError> {
+    let area = parse_input(text)?;
+    let (mut velocity, highpoint) = highest_shot(&Pos(0, 0), &area).unwrap_or((Velocity(0, 0), 0));
+    let start = velocity;
+
+    let mut pos = Pos(0, 0);
+    let mut positions = vec![pos];
+    while !pos.is_in(&area) && (velocity.1 >= 0 || pos.1 >= area.y_start) {
+        pos = pos.apply_velocity(&velocity);
+        velocity = velocity.step();
+        positions.push(pos);
+    }
+
+    let (min_x, max_x) = (area.x_start.min(0), area.x_end.max(0));
+    let (min_y, max_y) = (area.y_start.min(0), highpoint.max(area.y_end).max(0));
+    let margin = (max_x - min_x).max(max_y - min_y) as f64 / 20.0;
+    let mut svg = Svg::new(
+        min_x as f64 - margin,
+        -max_y as f64 - margin,
+        (max_x - min_x) as f64 + 2.0 * margin,
+        (max_y - min_y) as f64 + 2.0 * margin,
+    );
+
+    svg.rect(
+        area.x_start as f64,
+        -area.y_end as f64,
+        (area.x_end - area.x_start) as f64,
+        (area.y_end - area.y_start) as f64,
+        &Style::fill("#f46d43")
+            .with_opacity(0.4)
+            .with_stroke("#a50026", 1.0),
+    );
+    let points: Vec<_> = positions
+        .iter()
+        .map(|&Pos(x, y)| (x as f64, -y as f64))
+        .collect();
+    svg.polyline(&points, &Style::stroke("#4575b4", 1.5));
+    for &(x, y) in &points {
+        svg.point(x, y, 3.0, "#313695");
+    }
+
+    let Velocity(vx, vy) = start;
+    svg.text(
+        min_x as f64 - margin + 4.0 * svg.pixel(),
+        -max_y as f64 - margin + 16.0 * svg.pixel(),
+        &format!("velocity ({}, {}) reaches y={}", vx, vy, highpoint),
+        "black",
+    );
+    return Ok(svg);
+}
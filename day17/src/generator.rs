@@ -0,0 +1,17 @@
+//! Random target areas.
+
+use rand::Rng;
+
+/// Generates a target area to the right of and below the launcher whose distance and
+/// size grow with `scale`.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let scale = scale as i32;
+    let x_start = rng.gen_range(20..150) * scale;
+    let x_end = x_start + rng.gen_range(10..40) * scale;
+    let y_start = -rng.gen_range(50..150) * scale;
+    let y_end = (y_start + rng.gen_range(5..30) * scale).min(-1);
+    return format!(
+        "target area: x={}..{}, y={}..{}\n",
+        x_start, x_end, y_start, y_end
+    );
+}
@@ -0,0 +1,229 @@
+//! Day 17: Trick Shot
+
+use common::{parse_number, Input, ParseError, Solution};
+use std::error::Error;
+
+pub mod draw;
+pub mod generator;
+pub mod visualize;
+
+/// Finds the initial velocities that make the probe hit the target area.
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = TargetArea;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(text: &str) -> Result<TargetArea, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(target_area: &TargetArea) -> i32 {
+        let (_, highpoint) = find_highpoint(&Pos(0, 0), target_area);
+        highpoint.expect("no initial velocity hits the target area")
+    }
+
+    fn part2(target_area: &TargetArea) -> usize {
+        let (hits, _) = find_highpoint(&Pos(0, 0), target_area);
+        hits
+    }
+}
+
+/// Largest distance of the target area from the launcher, small enough for the highest
+/// `y` of any velocity that can hit it to fit into an `i32`.
+pub const MAX_COORDINATE: i32 = 40_000;
+
+/// Rectangular target area, with inclusive bounds.
+#[derive(Debug)]
+pub struct TargetArea {
+    pub x_start: i32,
+    pub x_end: i32,
+    pub y_start: i32,
+    pub y_end: i32,
+}
+
+/// Position of the probe.
+#[derive(Copy, Clone)]
+pub struct Pos(pub i32, pub i32);
+
+impl Pos {
+    /// Moves the position by one step of `velocity`.
+    pub fn apply_velocity(&self, Velocity(vx, vy): &Velocity) -> Self {
+        let Pos(x, y) = self;
+        Pos(x + vx, y + vy)
+    }
+
+    /// Returns whether the position lies within `target`.
+    pub fn is_in(&self, target: &TargetArea) -> bool {
+        let &Pos(x, y) = self;
+        target.x_start <= x && target.x_end >= x && target.y_start <= y && target.y_end >= y
+    }
+}
+
+/// Velocity of the probe.
+#[derive(Copy, Clone)]
+pub struct Velocity(pub i32, pub i32);
+
+impl Velocity {
+    /// Applies drag and gravity for one step.
+    pub fn step(&self) -> Self {
+        let &Velocity(vx, vy) = self;
+        if vx == 0 {
+            Velocity(vx, vy - 1)
+        } else if vx > 0 {
+            Velocity(vx - 1, vy - 1)
+        } else {
+            Velocity(vx + 1, vy - 1)
+        }
+    }
+}
+
+/// Returns the highest `y` of the trajectory if it hits the target area.
+pub fn get_highpoint(
+    start_velocity: &Velocity,
+    start_pos: &Pos,
+    target_area: &TargetArea,
+) -> Option<i32> {
+    let mut pos = *start_pos;
+    let mut velocity = *start_velocity;
+    let Pos(_, mut highpoint) = start_pos;
+
+    loop {
+        let Pos(x, y) = pos.apply_velocity(&velocity);
+        let Velocity(vx, vy) = velocity.step();
+
+        pos = Pos(x, y);
+        velocity = Velocity(vx, vy);
+
+        highpoint = i32::max(highpoint, y);
+
+        if pos.is_in(target_area) {
+            return Some(highpoint);
+        }
+
+        if vx == 0 && (x < target_area.x_start || x > target_area.x_end) {
+            return None;
+        }
+
+        if vy < 0 && y < target_area.y_start {
+            return None;
+        }
+    }
+}
+
+/// Returns the initial velocities that make the probe hit `target_area` from `start_pos`,
+/// each with the highest `y` of its trajectory.
+pub fn hits<'a>(
+    start_pos: &'a Pos,
+    target_area: &'a TargetArea,
+) -> impl Iterator<Item = (Velocity, i32)> + 'a {
+    // Faster shots overshoot the target area in their first step, or in the step after
+    // returning to the launcher's height
+    let Pos(x, y) = *start_pos;
+    let (x_start, x_end) = (target_area.x_start - x, target_area.x_end - x);
+    let (y_start, y_end) = (target_area.y_start - y, target_area.y_end - y);
+    let max_vy = y_start.abs().max(y_end.abs());
+    (x_start.min(0)..=x_end.max(0)).flat_map(move |vx| {
+        (y_start.min(0)..=max_vy).filter_map(move |vy| {
+            let velocity = Velocity(vx, vy);
+            get_highpoint(&velocity, start_pos, target_area).map(|highpoint| (velocity, highpoint))
+        })
+    })
+}
+
+/// Returns the number of velocities that hit the target area and the highest `y` among them.
+pub fn find_highpoint(start_pos: &Pos, target_area: &TargetArea) -> (usize, Option<i32>) {
+    let highpoints: Vec<i32> = hits(start_pos, target_area)
+        .map(|(_, highpoint)| highpoint)
+        .collect();
+    return (highpoints.len(), highpoints.iter().copied().max());
+}
+
+/// Returns the initial velocity reaching the highest `y` of those hitting `target_area`
+/// from `start_pos`, together with that `y`.
+pub fn highest_shot(start_pos: &Pos, target_area: &TargetArea) -> Option<(Velocity, i32)> {
+    hits(start_pos, target_area).reduce(|best, hit| if hit.1 > best.1 { hit } else { best })
+}
+
+/// Reads the target area from `input`.
+pub fn get_input(input: &Input) -> Result<TargetArea, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses a `target area: x=a..b, y=c..d` line.
+pub fn parse_input(text: &str) -> Result<TargetArea, ParseError> {
+    fn parse_range(text: &str, range: &str) -> Result<(i32, i32), ParseError> {
+        let (start, end) = range
+            .split_once("..")
+            .ok_or_else(|| ParseError::at(text, range, "expected a range `a..b`"))?;
+        Ok((parse_number(text, start)?, parse_number(text, end)?))
+    }
+
+    let (x_range, y_range) = text
+        .trim()
+        .strip_prefix("target area: x=")
+        .and_then(|ranges| ranges.split_once(", y="))
+        .ok_or_else(|| {
+            ParseError::at(text, text.trim(), "expected `target area: x=a..b, y=c..d`")
+        })?;
+    let (x_start, x_end) = parse_range(text, x_range)?;
+    let (y_start, y_end) = parse_range(text, y_range)?;
+    for (range, start, end) in [(x_range, x_start, x_end), (y_range, y_start, y_end)] {
+        if start > end {
+            return Err(ParseError::at(text, range, "range ends before it starts"));
+        }
+        if start.abs() > MAX_COORDINATE || end.abs() > MAX_COORDINATE {
+            let message = format!("coordinates must be within ±{}", MAX_COORDINATE);
+            return Err(ParseError::at(text, range, &message));
+        }
+    }
+    // Shots passing the launcher's height within the target area in `x` would hit it
+    // with any upward velocity
+    if y_start <= 0 && y_end >= 0 {
+        return Err(ParseError::at(
+            text,
+            y_range,
+            "target area must be above or below the launcher",
+        ));
+    }
+
+    Ok(TargetArea {
+        x_start,
+        x_end,
+        y_start,
+        y_end,
+    })
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day17>();
+}
+
+#[test]
+fn test_example() {
+    let target_area = parse_input("target area: x=20..30, y=-10..-5").unwrap();
+    let (hits, high_point) = find_highpoint(&Pos(0, 0), &target_area);
+    assert_eq!(hits, 112);
+    assert_eq!(high_point, Some(45));
+    let shot = highest_shot(&Pos(0, 0), &target_area);
+    assert_eq!(shot.map(|(_, highpoint)| highpoint), Some(45));
+
+    // Mirrored to the left of the launcher
+    let target_area = parse_input("target area: x=-30..-20, y=-10..-5").unwrap();
+    assert_eq!(find_highpoint(&Pos(0, 0), &target_area), (112, Some(45)));
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = parse_input("target area: x=30..20, y=-10..-5").unwrap_err();
+    assert_eq!(
+        (err.column, err.message.as_str()),
+        (16, "range ends before it starts")
+    );
+    assert!(parse_input("target area: x=20..30, y=-10..5").is_err());
+    assert!(parse_input("target area: x=20..30, y=-100000..-5").is_err());
+}
@@ -0,0 +1,133 @@
+//! Animation of the highest shot hitting the target area.
+
+use crate::{highest_shot, parse_input, Pos, TargetArea, Velocity};
+use common::visualize::{Color, Frame, Simulation};
+use common::ParseError;
+
+/// Size of the frame the trajectory is scaled to.
+const WIDTH: usize = 100;
+const HEIGHT: usize = 40;
+
+/// Probe launched with the velocity reaching the highest `y`, moved once per step.
+pub struct Trajectory {
+    target_area: TargetArea,
+    highpoint: i32,
+    positions: Vec<Pos>,
+    velocity: Velocity,
+}
+
+impl Trajectory {
+    fn is_finished(&self) -> bool {
+        let &Pos(x, y) = self.positions.last().expect("trajectory has a start");
+        let area = &self.target_area;
+        let falling_below = self.velocity.1 < 0 && y < area.y_start;
+        Pos(x, y).is_in(area) || falling_below
+    }
+}
+
+impl Simulation for Trajectory {
+    fn step(&mut self) -> bool {
+        if self.is_finished() {
+            return false;
+        }
+        let pos = self
+            .positions
+            .last()
+            .unwrap()
+            .apply_velocity(&self.velocity);
+        self.positions.push(pos);
+        self.velocity = self.velocity.step();
+        true
+    }
+
+    fn render(&self) -> Frame {
+        let &Pos(x, y) = self.positions.last().unwrap();
+        let Velocity(vx, vy) = self.velocity;
+        let mut status = format!(
+            "step {}, position ({}, {}), velocity ({}, {})",
+            self.positions.len() - 1,
+            x,
+            y,
+            vx,
+            vy
+        );
+        if Pos(x, y).is_in(&self.target_area) {
+            status.push_str(&format!(", hit after reaching y={}", self.highpoint));
+        }
+
+        // Scale the region from the start to the target area and the highpoint to the frame
+        let area = &self.target_area;
+        let (min_x, max_x) = (0.min(area.x_start), area.x_end.max(0));
+        let (min_y, max_y) = (area.y_start.min(0), self.highpoint.max(0));
+        let scale = |v: i32, min: i32, max: i32, size: usize| {
+            ((v - min) as i64 * (size as i64 - 1) / (max - min).max(1) as i64) as usize
+        };
+        // Positions outside of the region, like those of a shot missing the target area,
+        // are not drawn
+        let to_frame = |x: i32, y: i32| {
+            if x < min_x || x > max_x || y < min_y || y > max_y {
+                return None;
+            }
+            Some((
+                scale(x, min_x, max_x, WIDTH),
+                HEIGHT - 1 - scale(y, min_y, max_y, HEIGHT),
+            ))
+        };
+
+        let mut frame = Frame::new(status, WIDTH, HEIGHT);
+        let corners = (
+            to_frame(area.x_start, area.y_end),
+            to_frame(area.x_end, area.y_start),
+        );
+        if let (Some((left, top)), Some((right, bottom))) = corners {
+            for fx in left..=right {
+                for fy in top..=bottom {
+                    frame.set(fx, fy, '░', Color::Red);
+                }
+            }
+        }
+        for &Pos(x, y) in &self.positions {
+            if let Some((fx, fy)) = to_frame(x, y) {
+                frame.set(fx, fy, '#', Color::Yellow);
+            }
+        }
+        if let Some((fx, fy)) = to_frame(x, y) {
+            frame.set(fx, fy, 'o', Color::White);
+        }
+        frame
+    }
+}
+
+/// Creates the simulation of the highest shot at the target area in `text`.
+pub fn simulation(text: &str) -> Result<Box<dyn Simulation>, ParseError> {
+    let target_area = parse_input(text)?;
+    let (velocity, highpoint) =
+        highest_shot(&Pos(0, 0), &target_area).unwrap_or((Velocity(0, 0), 0));
+    Ok(Box::new(Trajectory {
+        target_area,
+        highpoint,
+        positions: vec![Pos(0, 0)],
+        velocity,
+    }))
+}
+
+#[test]
+fn test_render_outside() {
+    // A shot falling through the bottom of the region is only drawn while inside it
+    let trajectory = Trajectory {
+        target_area: parse_input("target area: x=20..30, y=-10..-5").unwrap(),
+        highpoint: 0,
+        positions: (0..12).map(|y| Pos(0, -y)).collect(),
+        velocity: Velocity(0, -12),
+    };
+    let frame = trajectory.render();
+    let cells: Vec<(char, Color)> = frame.rows.iter().flatten().copied().collect();
+
+    // The eleven positions from y=0 down to the bottom of the target area, from the top
+    // row to the bottom one, but not the current position below it
+    assert_eq!(frame.rows[0][0], ('#', Color::Yellow));
+    assert_eq!(frame.rows[HEIGHT - 1][0], ('#', Color::Yellow));
+    assert_eq!(cells.iter().filter(|&&(c, _)| c == '#').count(), 11);
+    assert!(!cells.iter().any(|&(c, _)| c == 'o'));
+    assert_eq!(frame.rows[HEIGHT - 1][WIDTH - 1], ('░', Color::Red));
+}
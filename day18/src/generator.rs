@@ -0,0 +1,30 @@
+//! Random snailfish homework.
+
+use rand::Rng;
+
+/// Generates `100 * scale` reduced snailfish numbers, nested at most four pairs deep and
+/// with regular numbers below 10.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..100 * scale {
+        write_pair(rng, 1, &mut text);
+        text.push('\n');
+    }
+    return text;
+}
+
+/// Appends a random pair nested `depth` pairs deep.
+fn write_pair<R: Rng>(rng: &mut R, depth: usize, text: &mut String) {
+    text.push('[');
+    for i in 0..2 {
+        if i == 1 {
+            text.push(',');
+        }
+        if depth < 4 && rng.gen_bool(0.6) {
+            write_pair(rng, depth + 1, text);
+        } else {
+            text.push(char::from_digit(rng.gen_range(0..10), 10).unwrap());
+        }
+    }
+    text.push(']');
+}
@@ -0,0 +1,399 @@
+//! Day 18: Snailfish
+
+use common::{parse_number, Input, ParseError, Solution};
+use std::error::Error;
+use std::fmt;
+
+pub mod generator;
+
+/// Does the snailfish homework.
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<Number>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(text: &str) -> Result<Vec<Number>, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(numbers: &Vec<Number>) -> i64 {
+        add_number_list(numbers).magnitude()
+    }
+
+    fn part2(numbers: &Vec<Number>) -> i64 {
+        find_largest_sum_magnitude(numbers)
+    }
+}
+
+/// Snailfish number, either a regular number or a pair.
+#[derive(Clone, Eq, PartialEq)]
+pub enum Number {
+    Regular(i64),
+    Pair(Box<Number>, Box<Number>),
+}
+
+impl Number {
+    /// Parses a number such as `[[1,2],3]`.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        // Pairs that are still open, with their left element once it has been read
+        let mut open: Vec<Option<Number>> = Vec::new();
+        let mut chars = text.char_indices().peekable();
+        loop {
+            // Open pairs until an element starts with a regular number
+            let mut number = match chars.next() {
+                Some((_, '[')) => {
+                    open.push(None);
+                    continue;
+                }
+                Some((start, c)) if c.is_ascii_digit() => {
+                    let mut end = start + 1;
+                    while let Some((i, _)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                        end = i + 1;
+                    }
+                    Number::Regular(parse_number(text, &text[start..end])?)
+                }
+                Some((i, c)) => {
+                    let c = &text[i..i + c.len_utf8()];
+                    return Err(ParseError::at(text, c, "expected a number or pair"));
+                }
+                None => return Err(ParseError::at_end(text, "expected a number or pair")),
+            };
+
+            // Close the pairs the element completes
+            loop {
+                match (open.last_mut(), chars.next()) {
+                    (None, None) => return Ok(number),
+                    (Some(left @ None), Some((_, ','))) => {
+                        *left = Some(number);
+                        break;
+                    }
+                    (Some(Some(_)), Some((_, ']'))) => {
+                        let left = open.pop().unwrap().unwrap();
+                        number = Number::pair(left, number);
+                    }
+                    (_, Some((i, c))) => {
+                        let c = &text[i..i + c.len_utf8()];
+                        return Err(ParseError::at(text, c, "unexpected character"));
+                    }
+                    (Some(_), None) => return Err(ParseError::at_end(text, "pair is not closed")),
+                }
+            }
+        }
+    }
+
+    /// Parses one number per item of `it`.
+    pub fn parse_list<'a, I>(it: I) -> Result<Vec<Number>, ParseError>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        it.into_iter()
+            .enumerate()
+            .map(|(i, text)| Number::parse(text).map_err(|e| e.below(i)))
+            .collect()
+    }
+
+    /// Creates the pair `[left,right]`.
+    pub fn pair(left: Number, right: Number) -> Self {
+        Number::Pair(Box::new(left), Box::new(right))
+    }
+
+    /// Returns the magnitude of the number.
+    pub fn magnitude(&self) -> i64 {
+        match self {
+            Number::Regular(value) => *value,
+            Number::Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Number::Regular(value) => write!(f, "{}", value),
+            Number::Pair(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+impl fmt::Debug for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Number::Regular(value) => write!(f, "{}", value),
+            Number::Pair(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+/// Adds two numbers and reduces the result.
+pub fn add_numbers(left: &Number, right: &Number) -> Number {
+    reduce(&Number::pair(left.clone(), right.clone()))
+}
+
+/// Adds up all numbers in order.
+pub fn add_number_list(numbers: &[Number]) -> Number {
+    let mut sum = numbers[0].clone();
+    for n in &numbers[1..] {
+        sum = add_numbers(&sum, n);
+    }
+    return sum;
+}
+
+fn explode(number: &Number, nested: u32) -> (Number, bool, i64, i64) {
+    match number {
+        Number::Regular(value) => (Number::Regular(*value), false, 0, 0),
+        Number::Pair(left, right) if nested >= 4 => match (left.as_ref(), right.as_ref()) {
+            (Number::Regular(l), Number::Regular(r)) => (Number::Regular(0), true, *l, *r),
+            _ => panic!("unexpected pair"),
+        },
+        Number::Pair(left, right) => {
+            let (new_number_left, reduced, add_left, add_right) = explode(left, nested + 1);
+            if reduced {
+                (
+                    Number::pair(new_number_left, add_left_most(right, add_right)),
+                    true,
+                    add_left,
+                    0,
+                )
+            } else {
+                let (new_number_right, reduced2, add_left, add_right) = explode(right, nested + 1);
+                (
+                    Number::pair(add_right_most(left, add_left), new_number_right),
+                    reduced2,
+                    0,
+                    add_right,
+                )
+            }
+        }
+    }
+}
+
+fn split(number: &Number) -> (Number, bool) {
+    match number {
+        Number::Regular(value) if *value >= 10 => (
+            Number::Pair(
+                Box::new(Number::Regular(*value / 2)),
+                Box::new(Number::Regular(*value / 2 + *value % 2)),
+            ),
+            true,
+        ),
+        Number::Regular(value) => (Number::Regular(*value), false),
+        Number::Pair(left, right) => {
+            let (new_number_left, reduced) = split(left);
+            if reduced {
+                (Number::pair(new_number_left, right.as_ref().clone()), true)
+            } else {
+                let (new_number_right, reduced2) = split(right);
+                (
+                    Number::pair(left.as_ref().clone(), new_number_right),
+                    reduced2,
+                )
+            }
+        }
+    }
+}
+
+fn add_left_most(number: &Number, add: i64) -> Number {
+    match number {
+        Number::Regular(value) => Number::Regular(value + add),
+        Number::Pair(left, right) => Number::pair(add_left_most(left, add), right.as_ref().clone()),
+    }
+}
+
+fn add_right_most(number: &Number, add: i64) -> Number {
+    match number {
+        Number::Regular(value) => Number::Regular(value + add),
+        Number::Pair(left, right) => {
+            Number::pair(left.as_ref().clone(), add_right_most(right, add))
+        }
+    }
+}
+
+/// Explodes and splits the number until neither applies.
+pub fn reduce(number: &Number) -> Number {
+    let mut current_number = number.clone();
+    loop {
+        let (exploded_number, reduced, _, _) = explode(&current_number, 0);
+        if reduced {
+            current_number = exploded_number;
+            continue;
+        }
+
+        let (split_number, reduced) = split(&current_number);
+        if reduced {
+            current_number = split_number;
+            continue;
+        }
+
+        return split_number;
+    }
+}
+
+/// Returns the largest magnitude of the sum of any two different numbers.
+pub fn find_largest_sum_magnitude(numbers: &[Number]) -> i64 {
+    let mut max_magnitude = 0;
+    for (ix, x) in numbers.iter().enumerate() {
+        for (iy, y) in numbers.iter().enumerate() {
+            if ix != iy {
+                max_magnitude = max_magnitude.max(add_numbers(x, y).magnitude());
+                max_magnitude = max_magnitude.max(add_numbers(y, x).magnitude());
+            }
+        }
+    }
+
+    max_magnitude
+}
+
+/// Reads the homework numbers from `input`.
+pub fn get_input(input: &Input) -> Result<Vec<Number>, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses one number per line.
+pub fn parse_input(text: &str) -> Result<Vec<Number>, ParseError> {
+    Number::parse_list(text.trim().lines())
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day18>();
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        Number::parse("[[1,9],[8,5]]").unwrap(),
+        Number::pair(
+            Number::pair(Number::Regular(1), Number::Regular(9)),
+            Number::pair(Number::Regular(8), Number::Regular(5))
+        )
+    );
+}
+
+#[test]
+fn test_reduce() {
+    assert_eq!(
+        reduce(&Number::parse("[[[[[9,8],1],2],3],4]").unwrap()),
+        Number::parse("[[[[0,9],2],3],4]").unwrap()
+    );
+    assert_eq!(
+        reduce(&Number::parse("[7,[6,[5,[4,[3,2]]]]]").unwrap()),
+        Number::parse("[7,[6,[5,[7,0]]]]").unwrap()
+    );
+    assert_eq!(
+        reduce(&Number::parse("[[6,[5,[4,[3,2]]]],1]").unwrap()),
+        Number::parse("[[6,[5,[7,0]]],3]").unwrap()
+    );
+    assert_eq!(
+        reduce(&Number::parse("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]").unwrap()),
+        Number::parse("[[3,[2,[8,0]]],[9,[5,[7,0]]]]").unwrap()
+    );
+}
+
+#[test]
+fn test_addition() {
+    let sum = add_numbers(
+        &Number::parse("[[[[4,3],4],4],[7,[[8,4],9]]]").unwrap(),
+        &Number::parse("[1,1]").unwrap(),
+    );
+    assert_eq!(
+        sum,
+        Number::parse("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap()
+    );
+    assert_eq!(
+        add_numbers(
+            &Number::parse("[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]").unwrap(),
+            &Number::parse("[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]").unwrap(),
+        ),
+        Number::parse("[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]").unwrap()
+    );
+}
+
+#[test]
+fn test_add_number_list() {
+    assert_eq!(
+        add_number_list(&Number::parse_list(vec!["[1,1]", "[2,2]", "[3,3]", "[4,4]"]).unwrap()),
+        Number::parse("[[[[1,1],[2,2]],[3,3]],[4,4]]").unwrap()
+    );
+    assert_eq!(
+        add_number_list(
+            &Number::parse_list(vec!["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]"]).unwrap()
+        ),
+        Number::parse("[[[[3,0],[5,3]],[4,4]],[5,5]]").unwrap()
+    );
+    assert_eq!(
+        add_number_list(
+            &Number::parse_list(vec!["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]", "[6,6]"])
+                .unwrap()
+        ),
+        Number::parse("[[[[5,0],[7,4]],[5,5]],[6,6]]").unwrap()
+    );
+    assert_eq!(
+        add_number_list(
+            &Number::parse_list(vec![
+                "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
+                "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
+                "[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]",
+                "[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]",
+                "[7,[5,[[3,8],[1,4]]]]",
+                "[[2,[2,2]],[8,[8,1]]]",
+                "[2,9]",
+                "[1,[[[9,3],9],[[9,0],[0,7]]]]",
+                "[[[5,[7,4]],7],1]",
+                "[[[[4,2],2],6],[8,7]]",
+            ])
+            .unwrap()
+        ),
+        Number::parse("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").unwrap()
+    );
+}
+
+#[test]
+fn test_example_homework() {
+    let numbers = parse_input(&common::fixtures::fixture(18, "homework")).unwrap();
+    let sum = add_number_list(&numbers);
+    assert_eq!(
+        sum,
+        Number::parse("[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]").unwrap()
+    );
+    assert_eq!(sum.magnitude(), 4140);
+}
+
+#[test]
+fn test_parse_errors() {
+    let message = |text| Number::parse(text).unwrap_err().message;
+    assert_eq!(message("[1,2"), "pair is not closed");
+    assert_eq!(message("1,2]"), "unexpected character");
+    assert_eq!(message("[1[2,3]]"), "unexpected character");
+    assert_eq!(message("[1,]"), "expected a number or pair");
+    assert_eq!(message("[1,2]]"), "unexpected character");
+}
+
+/// Strategy generating numbers nested a few levels deep.
+#[cfg(test)]
+fn arbitrary_number() -> impl proptest::strategy::Strategy<Value = Number> {
+    use proptest::prelude::*;
+
+    let regular = (0..i64::MAX).prop_map(Number::Regular);
+    regular.prop_recursive(6, 64, 2, |inner| {
+        (inner.clone(), inner).prop_map(|(left, right)| Number::pair(left, right))
+    })
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_display_round_trip(number in arbitrary_number()) {
+        proptest::prop_assert_eq!(Number::parse(&number.to_string()), Ok(number));
+    }
+
+    #[test]
+    fn test_parse_never_panics(text in "[\\[\\],0-9]{0,32}|\\PC{0,16}") {
+        if let Ok(number) = Number::parse(&text) {
+            proptest::prop_assert_eq!(Number::parse(&number.to_string()), Ok(number));
+        }
+    }
+}
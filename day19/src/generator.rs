@@ -0,0 +1,67 @@
+//! Random scanner reports.
+
+use crate::{rotate, rotations, Point, MIN_OVERLAP};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::BTreeSet;
+
+/// Distance along each axis up to which a scanner detects beacons.
+const RANGE: i32 = 1000;
+
+/// Generates the reports of `30 * scale` scanners, each placed near the one before and
+/// sharing at least [`MIN_OVERLAP`] beacons with it. Every report lists all beacons in
+/// range of its scanner, shuffled and seen from a random rotation.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    fn random_point<R: Rng>(rng: &mut R, low: Point, high: Point) -> Point {
+        (
+            rng.gen_range(low.0..=high.0),
+            rng.gen_range(low.1..=high.1),
+            rng.gen_range(low.2..=high.2),
+        )
+    }
+
+    let mut scanners: Vec<Point> = vec![(0, 0, 0)];
+    let mut beacons = BTreeSet::new();
+    while scanners.len() < 30 * scale {
+        let (px, py, pz) = *scanners.last().unwrap();
+        let (sx, sy, sz) = random_point(rng, (-1200, -1200, -1200), (1200, 1200, 1200));
+        let (sx, sy, sz) = (px + sx, py + sy, pz + sz);
+
+        // Beacons in range of both scanners
+        let low = (px.max(sx) - RANGE, py.max(sy) - RANGE, pz.max(sz) - RANGE);
+        let high = (px.min(sx) + RANGE, py.min(sy) + RANGE, pz.min(sz) + RANGE);
+        let shared = MIN_OVERLAP + rng.gen_range(0..4);
+        let before = beacons.len();
+        while beacons.len() < before + shared {
+            beacons.insert(random_point(rng, low, high));
+        }
+
+        // Beacons of its own
+        for _ in 0..rng.gen_range(0..10) {
+            let low = (sx - RANGE, sy - RANGE, sz - RANGE);
+            let high = (sx + RANGE, sy + RANGE, sz + RANGE);
+            beacons.insert(random_point(rng, low, high));
+        }
+        scanners.push((sx, sy, sz));
+    }
+
+    let rotations = rotations();
+    let mut text = String::new();
+    for (id, &(sx, sy, sz)) in scanners.iter().enumerate() {
+        let rotation = rotations.choose(rng).unwrap();
+        let mut report: Vec<Point> = beacons
+            .iter()
+            .map(|&(x, y, z)| (x - sx, y - sy, z - sz))
+            .filter(|&(x, y, z)| x.abs() <= RANGE && y.abs() <= RANGE && z.abs() <= RANGE)
+            .map(|beacon| rotate(rotation, beacon))
+            .collect();
+        report.shuffle(rng);
+
+        text.push_str(&format!("--- scanner {} ---\n", id));
+        for (x, y, z) in report {
+            text.push_str(&format!("{},{},{}\n", x, y, z));
+        }
+        text.push('\n');
+    }
+    return text;
+}
@@ -0,0 +1,257 @@
+//! Day 19: Beacon Scanner
+
+use common::{parse_lines, sections, Input, ParseError, Parser, Solution};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+
+pub mod generator;
+
+/// Assembles the map of beacons from the overlapping scanner reports.
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(text: &str) -> Result<Map, ParseError> {
+        // Aligning the scanners is most of the work, so it is done once for both parts
+        let scanners = parse_input(text)?;
+        align_scanners(&scanners).map_err(|i| {
+            let section = sections(text).nth(i).expect("each scanner has a section");
+            let header = section.lines().next().unwrap_or(section);
+            ParseError::at(text, header, "scanner does not overlap any of the others")
+        })
+    }
+
+    fn part1(map: &Map) -> usize {
+        map.beacons.len()
+    }
+
+    fn part2(map: &Map) -> i32 {
+        largest_distance(&map.scanners)
+    }
+}
+
+/// Position `(x, y, z)` of a beacon or scanner.
+pub type Point = (i32, i32, i32);
+
+/// Rotation of a scanner as the matrix mapping its coordinates to the rotated ones.
+pub type Rotation = [[i32; 3]; 3];
+
+/// Number of beacons two scanners need to have in common to be aligned.
+pub const MIN_OVERLAP: usize = 12;
+
+/// Report of a single scanner, with the beacon positions relative to the scanner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scanner {
+    pub id: usize,
+    pub beacons: Vec<Point>,
+}
+
+/// All beacons and the scanner positions, relative to the first scanner.
+#[derive(Debug)]
+pub struct Map {
+    pub beacons: HashSet<Point>,
+    pub scanners: Vec<Point>,
+}
+
+/// Returns the 24 rotations, one for each direction a scanner can face combined with
+/// each of the four directions it can consider up.
+pub fn rotations() -> Vec<Rotation> {
+    let mut rotations = Vec::new();
+    for axes in [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ] {
+        for signs in 0..8 {
+            let mut rotation = [[0; 3]; 3];
+            for row in 0..3 {
+                rotation[row][axes[row]] = if signs >> row & 1 == 1 { -1 } else { 1 };
+            }
+            if determinant(&rotation) == 1 {
+                rotations.push(rotation);
+            }
+        }
+    }
+    return rotations;
+}
+
+fn determinant(m: &Rotation) -> i32 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// Applies `rotation` to `point`.
+pub fn rotate(rotation: &Rotation, (x, y, z): Point) -> Point {
+    let [a, b, c] = rotation;
+    (
+        a[0] * x + a[1] * y + a[2] * z,
+        b[0] * x + b[1] * y + b[2] * z,
+        c[0] * x + c[1] * y + c[2] * z,
+    )
+}
+
+/// Finds the rotation and position of `scanner` at which at least [`MIN_OVERLAP`] of its
+/// beacons coincide with `known` beacons. Returns its beacons at that rotation and
+/// position together with the position.
+pub fn align(known: &[Point], scanner: &Scanner) -> Option<(Vec<Point>, Point)> {
+    for rotation in rotations() {
+        let rotated: Vec<Point> = scanner
+            .beacons
+            .iter()
+            .map(|&b| rotate(&rotation, b))
+            .collect();
+
+        // Count how many beacon pairs agree on each possible scanner position
+        let mut offsets = HashMap::new();
+        for &(kx, ky, kz) in known {
+            for &(rx, ry, rz) in &rotated {
+                *offsets.entry((kx - rx, ky - ry, kz - rz)).or_insert(0) += 1;
+            }
+        }
+
+        if let Some((&(dx, dy, dz), _)) = offsets.iter().find(|&(_, &count)| count >= MIN_OVERLAP) {
+            let beacons = rotated
+                .iter()
+                .map(|&(x, y, z)| (x + dx, y + dy, z + dz))
+                .collect();
+            return Some((beacons, (dx, dy, dz)));
+        }
+    }
+
+    return None;
+}
+
+/// Aligns all scanners to the first one, each with a scanner aligned before it. Returns
+/// `None` if some scanner does not overlap any of the others, and an empty map for no
+/// scanners.
+pub fn locate_scanners(scanners: &[Scanner]) -> Option<Map> {
+    align_scanners(scanners).ok()
+}
+
+/// Aligns the scanners like [`locate_scanners`], failing with the index of the first
+/// scanner that does not overlap any of the others.
+fn align_scanners(scanners: &[Scanner]) -> Result<Map, usize> {
+    let mut positions = vec![None; scanners.len()];
+    let mut aligned = vec![Vec::new(); scanners.len()];
+    // Scanners that were aligned but not yet compared with the unaligned ones
+    let mut to_compare = Vec::new();
+    if let Some(first) = scanners.first() {
+        positions[0] = Some((0, 0, 0));
+        aligned[0] = first.beacons.clone();
+        to_compare.push(0);
+    }
+
+    while let Some(i) = to_compare.pop() {
+        for (j, scanner) in scanners.iter().enumerate() {
+            if positions[j].is_none() {
+                if let Some((beacons, position)) = align(&aligned[i], scanner) {
+                    positions[j] = Some(position);
+                    aligned[j] = beacons;
+                    to_compare.push(j);
+                }
+            }
+        }
+    }
+
+    if let Some(unaligned) = positions.iter().position(Option::is_none) {
+        return Err(unaligned);
+    }
+    let scanners = positions.into_iter().flatten().collect();
+    let beacons = aligned.into_iter().flatten().collect();
+    return Ok(Map { beacons, scanners });
+}
+
+/// Returns the largest Manhattan distance between any two of `points`.
+pub fn largest_distance(points: &[Point]) -> i32 {
+    let mut largest = 0;
+    for &(ax, ay, az) in points {
+        for &(bx, by, bz) in points {
+            largest = largest.max((ax - bx).abs() + (ay - by).abs() + (az - bz).abs());
+        }
+    }
+    return largest;
+}
+
+/// Reads the scanner reports from `input`.
+pub fn get_input(input: &Input) -> Result<Vec<Scanner>, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses the reports, each a `--- scanner N ---` line followed by one `x,y,z` beacon
+/// position per line.
+pub fn parse_input(text: &str) -> Result<Vec<Scanner>, ParseError> {
+    sections(text)
+        .map(|section| parse_scanner(section).map_err(|e| e.within(text, section)))
+        .collect()
+}
+
+fn parse_scanner(text: &str) -> Result<Scanner, ParseError> {
+    let (header, beacons) = text.split_once('\n').unwrap_or((text, ""));
+    let mut parser = Parser::new(header);
+    parser.tag("--- scanner ")?;
+    let id = parser.number()?;
+    parser.tag(" ---")?;
+    parser.end()?;
+
+    let beacons = parse_lines(beacons, |line| {
+        let mut parser = Parser::new(line);
+        let (x, (y, z)) = parser.separated(",", Parser::number, |parser| {
+            parser.separated(",", Parser::number, Parser::number)
+        })?;
+        parser.end()?;
+        Ok((x, y, z))
+    })
+    .map_err(|e| e.below(1))?;
+
+    return Ok(Scanner { id, beacons });
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day19>();
+}
+
+#[test]
+fn test_rotations() {
+    let rotations = rotations();
+    assert_eq!(rotations.len(), 24);
+
+    let rotated: HashSet<Point> = rotations.iter().map(|r| rotate(r, (1, 2, 3))).collect();
+    assert_eq!(rotated.len(), 24);
+}
+
+#[test]
+fn test_example() {
+    let scanners = parse_input(&common::fixtures::fixture(19, "example")).unwrap();
+    let map = locate_scanners(&scanners).unwrap();
+    assert_eq!(
+        map.scanners,
+        vec![
+            (0, 0, 0),
+            (68, -1246, -43),
+            (1105, -1205, 1229),
+            (-92, -2380, -20),
+            (-20, -1133, 1061),
+        ]
+    );
+    assert_eq!(map.beacons.len(), 79);
+    assert_eq!(largest_distance(&map.scanners), 3621);
+}
+
+#[test]
+fn test_unaligned() {
+    assert_eq!(locate_scanners(&[]).map(|map| map.scanners.len()), Some(0));
+
+    let err = Day19::parse("--- scanner 0 ---\n0,0,0\n\n--- scanner 1 ---\n1,1,1\n").unwrap_err();
+    assert_eq!((err.line, err.column), (4, 1));
+    assert_eq!(err.message, "scanner does not overlap any of the others");
+}
@@ -0,0 +1,3 @@
+fn main() {
+    common::run::<day19::Day19>();
+}
@@ -0,0 +1,24 @@
+//! Random images and enhancement algorithms.
+
+use rand::Rng;
+
+/// Generates an enhancement algorithm and a `100 * scale` by `100 * scale` image. If the
+/// algorithm lights up dark regions it darkens lit ones again, so the infinitely many
+/// pixels around the image are dark after every second enhancement.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let pixel = |lit: bool| if lit { '#' } else { '.' };
+
+    let mut algorithm: Vec<bool> = (0..512).map(|_| rng.gen()).collect();
+    if algorithm[0] {
+        algorithm[511] = false;
+    }
+    let mut text: String = algorithm.into_iter().map(pixel).collect();
+    text.push_str("\n\n");
+
+    let size = 100 * scale;
+    for _ in 0..size {
+        text.extend((0..size).map(|_| pixel(rng.gen())));
+        text.push('\n');
+    }
+    return text;
+}
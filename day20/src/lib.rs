@@ -0,0 +1,166 @@
+//! Day 20: Trench Map
+
+use common::{Input, ParseError, Solution};
+use grid::Grid;
+use std::error::Error;
+use std::fmt;
+
+pub mod generator;
+pub mod visualize;
+
+/// Enhances the infinite trench map image.
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = (EnhancementString, Image);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(text: &str) -> Result<(EnhancementString, Image), ParseError> {
+        parse_input(text)
+    }
+
+    fn part1((enhancement_string, image): &(EnhancementString, Image)) -> usize {
+        enhance_n_times(enhancement_string, image, 2).count_lit()
+    }
+
+    fn part2((enhancement_string, image): &(EnhancementString, Image)) -> usize {
+        enhance_n_times(enhancement_string, image, 50).count_lit()
+    }
+}
+
+/// Image enhancement algorithm, one output pixel per 9 bit neighbourhood.
+pub type EnhancementString = Vec<u8>;
+
+/// Parses the enhancement algorithm from a line of `#` and `.`.
+pub fn parse_enhancement_string(text: &str) -> Result<EnhancementString, ParseError> {
+    let enhancement_string = text
+        .char_indices()
+        .map(|(i, c)| {
+            parse_pixel(c).ok_or_else(|| {
+                ParseError::at(text, &text[i..i + c.len_utf8()], "expected `#` or `.`")
+            })
+        })
+        .collect::<Result<EnhancementString, _>>()?;
+
+    if enhancement_string.len() != 512 {
+        return Err(ParseError::at(text, text, "expected 512 pixels"));
+    }
+    Ok(enhancement_string)
+}
+
+fn parse_pixel(c: char) -> Option<u8> {
+    match c {
+        '#' => Some(1),
+        '.' => Some(0),
+        _ => None,
+    }
+}
+
+/// Finite region of an infinite image whose remaining pixels are all `infinity`.
+#[derive(Clone)]
+pub struct Image {
+    pub pixels: Grid<u8>,
+    pub infinity: u8,
+}
+
+impl Image {
+    /// Returns the pixel at `(x, y)`, which may lie outside the stored region.
+    pub fn get(&self, x: i32, y: i32) -> u8 {
+        self.pixels.get(x, y).copied().unwrap_or(self.infinity)
+    }
+
+    /// Counts the lit pixels of the stored region.
+    pub fn count_lit(&self) -> usize {
+        self.pixels.iter().map(|p| *p as usize).sum()
+    }
+
+    /// Parses one row of `#` and `.` pixels per line.
+    pub fn parse(text: &str) -> Result<Image, ParseError> {
+        Ok(Image {
+            pixels: Grid::parse(text, parse_pixel)?,
+            infinity: 0,
+        })
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.pixels.map(|&p| if p == 1 { '#' } else { '.' })
+        )
+    }
+}
+
+/// Applies the enhancement algorithm once, growing the image by one pixel on each side.
+pub fn enhance(enhancement_string: &[u8], image: &Image) -> Image {
+    let width = image.pixels.width() + 2;
+    let height = image.pixels.height() + 2;
+    let enhanced_pixels = Grid::from_fn(width, height, |x, y| {
+        let (x, y) = (x - 1, y - 1);
+        let index = (image.get(x - 1, y - 1) as usize) << 8
+            | (image.get(x, y - 1) as usize) << 7
+            | (image.get(x + 1, y - 1) as usize) << 6
+            | (image.get(x - 1, y) as usize) << 5
+            | (image.get(x, y) as usize) << 4
+            | (image.get(x + 1, y) as usize) << 3
+            | (image.get(x - 1, y + 1) as usize) << 2
+            | (image.get(x, y + 1) as usize) << 1
+            | (image.get(x + 1, y + 1) as usize);
+        enhancement_string[index]
+    });
+
+    let new_infinity = if image.infinity == 1 {
+        enhancement_string[511]
+    } else {
+        enhancement_string[0]
+    };
+
+    Image {
+        pixels: enhanced_pixels,
+        infinity: new_infinity,
+    }
+}
+
+/// Applies the enhancement algorithm `n` times.
+pub fn enhance_n_times(enhancement_string: &[u8], image: &Image, n: usize) -> Image {
+    (0..n).fold(image.clone(), |i, _| enhance(enhancement_string, &i))
+}
+
+/// Reads the enhancement algorithm and the input image from `input`.
+pub fn get_input(input: &Input) -> Result<(Vec<u8>, Image), Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses the enhancement algorithm followed by a blank line and the image.
+pub fn parse_input(text: &str) -> Result<(Vec<u8>, Image), ParseError> {
+    let text = text.trim();
+    let (enhancement_string, image) = text
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at_end(text, "expected an empty line followed by the image"))?;
+    let enhancement_string = parse_enhancement_string(enhancement_string)?;
+    let image = Image::parse(image).map_err(|e| e.within(text, image))?;
+
+    Ok((enhancement_string, image))
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day20>();
+}
+
+#[test]
+fn test_example() {
+    let (enhancement_string, image) =
+        parse_input(&common::fixtures::fixture(20, "example")).unwrap();
+    let image_1 = enhance(&enhancement_string, &image);
+    let image_2 = enhance(&enhancement_string, &image_1);
+    let image_50 = enhance_n_times(&enhancement_string, &image, 50);
+
+    assert_eq!(image_2.count_lit(), 35);
+    assert_eq!(image_50.count_lit(), 3351);
+}
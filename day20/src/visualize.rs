@@ -0,0 +1,55 @@
+//! Animation of the image enhancement.
+
+use crate::{enhance, parse_input, EnhancementString, Image};
+use common::visualize::{Color, Frame, Simulation};
+use common::ParseError;
+
+/// Number of times the image is enhanced, as in part 2.
+const ENHANCEMENTS: usize = 50;
+
+/// Image, enhanced once per step.
+pub struct Enhancement {
+    enhancement_string: EnhancementString,
+    image: Image,
+    steps: usize,
+}
+
+impl Simulation for Enhancement {
+    fn step(&mut self) -> bool {
+        if self.steps == ENHANCEMENTS {
+            return false;
+        }
+        self.image = enhance(&self.enhancement_string, &self.image);
+        self.steps += 1;
+        true
+    }
+
+    fn render(&self) -> Frame {
+        let status = format!(
+            "enhancement {} of {}, {} lit pixels",
+            self.steps,
+            ENHANCEMENTS,
+            self.image.count_lit()
+        );
+        let pixels = &self.image.pixels;
+        let mut frame = Frame::new(status, pixels.width() as usize, pixels.height() as usize);
+        for (x, y) in pixels.positions() {
+            let (c, color) = match pixels[(x, y)] {
+                1 => ('#', Color::Yellow),
+                _ => ('.', Color::Dim),
+            };
+            frame.set(x as usize, y as usize, c, color);
+        }
+        frame
+    }
+}
+
+/// Creates the simulation of the enhancement algorithm and image in `text`.
+pub fn simulation(text: &str) -> Result<Box<dyn Simulation>, ParseError> {
+    let (enhancement_string, image) = parse_input(text)?;
+    Ok(Box::new(Enhancement {
+        enhancement_string,
+        image,
+        steps: 0,
+    }))
+}
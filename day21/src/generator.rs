@@ -0,0 +1,13 @@
+//! Random starting positions.
+
+use rand::Rng;
+
+/// Generates the starting positions of both players. The game has no size to scale, so
+/// `scale` is ignored.
+pub fn generate<R: Rng>(rng: &mut R, _scale: usize) -> String {
+    return format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.gen_range(1..=10),
+        rng.gen_range(1..=10)
+    );
+}
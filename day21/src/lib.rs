@@ -0,0 +1,291 @@
+//! Day 21: Dirac Dice
+
+use common::answer::{Answer, Value};
+use common::{parse_lines, parse_number, Input, ParseError, Solution};
+use std::error::Error;
+use std::fmt;
+
+pub mod generator;
+
+/// Plays Dirac Dice with deterministic and quantum dice.
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = (i32, i32);
+    type Part1 = DeterministicOutcome;
+    type Part2 = QuantumOutcome;
+
+    fn parse(text: &str) -> Result<(i32, i32), ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(&(start_a, start_b): &(i32, i32)) -> DeterministicOutcome {
+        let (player_a, player_b) = play_game(start_a - 1, start_b - 1);
+        let losing_score = if player_a.has_won() {
+            player_b.score
+        } else {
+            player_a.score
+        };
+        DeterministicOutcome {
+            losing_score,
+            rolls: player_a.rolls + player_b.rolls,
+        }
+    }
+
+    fn part2(&(start_a, start_b): &(i32, i32)) -> QuantumOutcome {
+        let (wins_a, wins_b) = play_game_quantum(
+            PlayerQ::new(start_a as u8 - 1),
+            PlayerQ::new(start_b as u8 - 1),
+        );
+        QuantumOutcome { wins_a, wins_b }
+    }
+}
+
+/// Outcome of the deterministic game, the losing score times the number of rolls.
+#[derive(Debug, PartialEq)]
+pub struct DeterministicOutcome {
+    pub losing_score: i32,
+    pub rolls: i32,
+}
+
+impl fmt::Display for DeterministicOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.losing_score * self.rolls)
+    }
+}
+
+impl Answer for DeterministicOutcome {
+    fn to_json(&self) -> Value {
+        Value::from(self.losing_score * self.rolls)
+    }
+
+    fn details(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("losing_score", Value::from(self.losing_score)),
+            ("rolls", Value::from(self.rolls)),
+        ]
+    }
+}
+
+/// Universes won by each player in the quantum game, the answer being the larger count.
+#[derive(Debug, PartialEq)]
+pub struct QuantumOutcome {
+    pub wins_a: u64,
+    pub wins_b: u64,
+}
+
+impl fmt::Display for QuantumOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.wins_a.max(self.wins_b))
+    }
+}
+
+impl Answer for QuantumOutcome {
+    fn to_json(&self) -> Value {
+        Value::from(self.wins_a.max(self.wins_b))
+    }
+
+    fn details(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("wins_player_1", Value::from(self.wins_a)),
+            ("wins_player_2", Value::from(self.wins_b)),
+        ]
+    }
+}
+
+/// Die that rolls 1 to 100 in order and then starts over.
+pub struct DeterministicDice {
+    current: i32,
+}
+
+impl DeterministicDice {
+    /// Creates a die whose first roll is `start`.
+    pub fn start(start: i32) -> DeterministicDice {
+        assert!((1..=100).contains(&start));
+        DeterministicDice { current: start - 1 }
+    }
+}
+
+impl Iterator for DeterministicDice {
+    type Item = i32;
+    fn next(&mut self) -> Option<i32> {
+        let result = self.current + 1;
+        self.current = (self.current + 1) % 100;
+        Some(result)
+    }
+}
+
+/// Player of the deterministic game.
+pub struct Player {
+    pub position: i32,
+    pub score: i32,
+    pub rolls: i32,
+}
+
+impl Player {
+    /// Creates a player at `position` with no score.
+    pub fn new(position: i32) -> Self {
+        Player {
+            position,
+            score: 0,
+            rolls: 0,
+        }
+    }
+
+    /// Returns whether the player reached 1000 points.
+    pub fn has_won(&self) -> bool {
+        self.score >= 1000
+    }
+
+    /// Rolls the die three times and moves the player.
+    pub fn turn(&mut self, dice: &mut DeterministicDice) {
+        self.position =
+            (self.position + dice.next().unwrap() + dice.next().unwrap() + dice.next().unwrap())
+                % 10;
+        self.score += self.position + 1;
+        self.rolls += 3;
+    }
+}
+
+/// Plays the deterministic game until one player wins.
+pub fn play_game(player_a_start: i32, player_b_start: i32) -> (Player, Player) {
+    let mut player_a = Player::new(player_a_start);
+    let mut player_b = Player::new(player_b_start);
+    let mut dice = DeterministicDice::start(1);
+
+    while !player_a.has_won() && !player_b.has_won() {
+        player_a.turn(&mut dice);
+        if !player_a.has_won() {
+            player_b.turn(&mut dice);
+        }
+    }
+
+    return (player_a, player_b);
+}
+
+/// Player of the quantum game.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct PlayerQ {
+    pos: u8,
+    score: u8,
+}
+impl PlayerQ {
+    /// Creates a player at `starting_pos` with no score.
+    pub fn new(starting_pos: u8) -> Self {
+        PlayerQ {
+            pos: starting_pos,
+            score: 0,
+        }
+    }
+
+    /// Moves the player by the sum `roll` of three rolls.
+    pub fn turn(&self, roll: u8) -> Self {
+        let new_pos = (self.pos + roll) % 10;
+        PlayerQ {
+            pos: new_pos,
+            score: self.score + new_pos + 1,
+        }
+    }
+
+    /// Returns whether the player reached 21 points.
+    pub fn has_won(&self) -> bool {
+        self.score >= 21
+    }
+}
+
+static ROLLS_QUANTITIES: &[(u8, u64)] = &[(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+/// Counts the universes in which each player wins.
+pub fn play_game_quantum(player_a: PlayerQ, player_b: PlayerQ) -> (u64, u64) {
+    use std::collections::HashMap;
+
+    type WinMap = HashMap<(PlayerQ, PlayerQ), (u64, u64)>;
+
+    let mut win_map: WinMap = HashMap::new();
+
+    fn get(win_map: &mut WinMap, player_a: PlayerQ, player_b: PlayerQ) -> (u64, u64) {
+        if player_a.has_won() || player_b.has_won() {
+            panic!();
+        }
+
+        match win_map.get(&(player_a, player_b)) {
+            Some(&(wins_a, wins_b)) => (wins_a, wins_b),
+            None => {
+                let result = calc(win_map, player_a, player_b);
+                win_map.insert((player_a, player_b), result);
+                return result;
+            }
+        }
+    }
+
+    fn calc(win_map: &mut WinMap, player_a: PlayerQ, player_b: PlayerQ) -> (u64, u64) {
+        let mut wins_a = 0;
+        let mut wins_b = 0;
+        for &(roll_a, qty_a) in ROLLS_QUANTITIES {
+            let player_a_new = player_a.turn(roll_a);
+            if player_a_new.has_won() {
+                wins_a += qty_a;
+                continue;
+            }
+
+            for &(roll_b, qty_b) in ROLLS_QUANTITIES {
+                let player_b_new = player_b.turn(roll_b);
+                if player_b_new.has_won() {
+                    wins_b += qty_a * qty_b;
+                    continue;
+                }
+
+                let (rec_wins_a, rec_wins_b) = get(win_map, player_a_new, player_b_new);
+                wins_a += qty_a * qty_b * rec_wins_a;
+                wins_b += qty_a * qty_b * rec_wins_b;
+            }
+        }
+
+        return (wins_a, wins_b);
+    }
+
+    return get(&mut win_map, player_a, player_b);
+}
+
+/// Reads the starting positions from `input`.
+pub fn get_input(input: &Input) -> Result<(i32, i32), Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses the `Player N starting position: P` lines.
+pub fn parse_input(text: &str) -> Result<(i32, i32), ParseError> {
+    let positions: Vec<i32> = parse_lines(text.trim_end(), |line| {
+        parse_number(line, line.rsplit(": ").next().unwrap_or(line))
+    })?;
+
+    if let [a, b] = positions[..] {
+        Ok((a, b))
+    } else {
+        Err(ParseError::at_end(
+            text.trim_end(),
+            "expected starting positions of two players",
+        ))
+    }
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day21>();
+}
+
+#[test]
+fn test_game() {
+    let (player_a, player_b) = play_game(4 - 1, 8 - 1);
+    assert!(player_a.has_won());
+    assert_eq!(player_b.score, 745);
+    assert_eq!(player_a.rolls + player_b.rolls, 993);
+}
+
+#[test]
+fn test_game_quantum() {
+    let (wins_a, wins_b) = play_game_quantum(PlayerQ::new(4 - 1), PlayerQ::new(8 - 1));
+    assert_eq!(wins_a, 444356092776315);
+    assert_eq!(wins_b, 341960390180808);
+}
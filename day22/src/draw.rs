@@ -0,0 +1,64 @@
+//! Picture of the reboot steps projected onto the x-y plane.
+
+use crate::{parse_input, Cuboid, CuboidToggle, CORE};
+use common::svg::{Style, Svg};
+use common::ParseError;
+
+/// Draws the cuboids of the reboot steps in `text` seen from above, in the order of the
+/// steps, those turning cubes on in green and off in red. The initialization procedure
+/// region is outlined.
+pub fn drawing(text: &str) -> Result<Svg, ParseError> {
+    let toggles = parse_input(text)?;
+    let bounds = toggles
+        .iter()
+        .map(|CuboidToggle(_, cuboid)| *cuboid)
+        .fold(CORE, |a, b| Cuboid {
+            x_start: a.x_start.min(b.x_start),
+            x_end: a.x_end.max(b.x_end),
+            y_start: a.y_start.min(b.y_start),
+            y_end: a.y_end.max(b.y_end),
+            ..a
+        });
+    let width = (bounds.x_end - bounds.x_start + 1) as f64;
+    let height = (bounds.y_end - bounds.y_start + 1) as f64;
+    let margin = width.max(height) / 20.0;
+    let mut svg = Svg::new(
+        bounds.x_start as f64 - margin,
+        bounds.y_start as f64 - margin,
+        width + 2.0 * margin,
+        height + 2.0 * margin,
+    );
+
+    let on = Style::fill("#1a9850").with_opacity(0.25);
+    let off = Style::fill("#d73027").with_opacity(0.25);
+    for CuboidToggle(toggle, c) in &toggles {
+        svg.rect(
+            c.x_start as f64,
+            c.y_start as f64,
+            (c.x_end - c.x_start + 1) as f64,
+            (c.y_end - c.y_start + 1) as f64,
+            if *toggle { &on } else { &off },
+        );
+    }
+    svg.rect(
+        CORE.x_start as f64,
+        CORE.y_start as f64,
+        (CORE.x_end - CORE.x_start + 1) as f64,
+        (CORE.y_end - CORE.y_start + 1) as f64,
+        &Style::stroke("black", 1.0).dashed(),
+    );
+
+    let on_count = toggles.iter().filter(|CuboidToggle(on, _)| *on).count();
+    svg.text(
+        bounds.x_start as f64 - margin + 4.0 * svg.pixel(),
+        bounds.y_start as f64 - margin + 16.0 * svg.pixel(),
+        &format!(
+            "{} steps, {} on, {} off",
+            toggles.len(),
+            on_count,
+            toggles.len() - on_count
+        ),
+        "black",
+    );
+    return Ok(svg);
+}
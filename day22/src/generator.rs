@@ -0,0 +1,35 @@
+//! Random reboot steps.
+
+use rand::Rng;
+
+/// Generates `420 * scale` reboot steps like the puzzle's: the first `20 * scale` turn
+/// cuboids within the initialization region on and off, the remaining ones span up to
+/// 100000 cubes in each direction.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let mut text = String::new();
+    for i in 0..420 * scale {
+        let (bound, max_size) = if i < 20 * scale {
+            (50, 50)
+        } else {
+            (100_000, 50_000)
+        };
+        let state = if i == 0 || rng.gen_bool(0.6) {
+            "on"
+        } else {
+            "off"
+        };
+        let mut range = || {
+            let start = rng.gen_range(-bound..bound);
+            let end = (start + rng.gen_range(0..=max_size)).min(bound);
+            format!("{}..{}", start, end)
+        };
+        text.push_str(&format!(
+            "{} x={},y={},z={}\n",
+            state,
+            range(),
+            range(),
+            range()
+        ));
+    }
+    return text;
+}
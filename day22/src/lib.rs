@@ -0,0 +1,320 @@
+//! Day 22: Reactor Reboot
+
+use common::{parse_lines, Input, ParseError, Parser, Solution};
+use itertools::Itertools;
+use std::error::Error;
+use std::fmt;
+
+pub mod draw;
+pub mod generator;
+
+/// Reboots the reactor by toggling cuboids.
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Vec<CuboidToggle>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(text: &str) -> Result<Vec<CuboidToggle>, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(toggles: &Vec<CuboidToggle>) -> i64 {
+        let core: Vec<_> = toggles
+            .iter()
+            .filter(|CuboidToggle(_, cuboid)| cuboid.is_in(&CORE))
+            .copied()
+            .collect();
+        count_on(&core)
+    }
+
+    fn part2(toggles: &Vec<CuboidToggle>) -> i64 {
+        count_on(toggles)
+    }
+}
+
+/// Counts the cubes that are on after applying all toggles.
+pub fn count_on(toggles: &[CuboidToggle]) -> i64 {
+    let mut count: i64 = 0;
+    for (z_start, z_end) in toggles
+        .iter()
+        .flat_map(|CuboidToggle(_, cuboid)| {
+            vec![
+                cuboid.z_start - 1,
+                cuboid.z_start,
+                cuboid.z_end,
+                cuboid.z_end + 1,
+            ]
+        })
+        .sorted()
+        .dedup()
+        .tuple_windows()
+    {
+        let zs: Vec<&CuboidToggle> = toggles
+            .iter()
+            .filter(|CuboidToggle(_, cuboid)| cuboid.z_start <= z_start && z_start <= cuboid.z_end)
+            .collect();
+        for (y_start, y_end) in zs
+            .iter()
+            .flat_map(|CuboidToggle(_, cuboid)| {
+                vec![
+                    cuboid.y_start - 1,
+                    cuboid.y_start,
+                    cuboid.y_end,
+                    cuboid.y_end + 1,
+                ]
+            })
+            .sorted()
+            .dedup()
+            .tuple_windows()
+        {
+            let zys: Vec<&&CuboidToggle> = zs
+                .iter()
+                .filter(|CuboidToggle(_, cuboid)| {
+                    cuboid.y_start <= y_start && y_start <= cuboid.y_end
+                })
+                .collect();
+            for (x_start, x_end) in zys
+                .iter()
+                .flat_map(|CuboidToggle(_, cuboid)| {
+                    vec![
+                        cuboid.x_start - 1,
+                        cuboid.x_start,
+                        cuboid.x_end,
+                        cuboid.x_end + 1,
+                    ]
+                })
+                .sorted()
+                .dedup()
+                .tuple_windows()
+            {
+                if let Some(CuboidToggle(true, _)) = zys.iter().rfind(|CuboidToggle(_, cuboid)| {
+                    cuboid.x_start <= x_start && x_start <= cuboid.x_end
+                }) {
+                    count += ((z_start - z_end).abs())
+                        * ((y_start - y_end).abs())
+                        * ((x_start - x_end).abs());
+                }
+            }
+        }
+    }
+
+    count
+}
+
+/// Cuboid with inclusive bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cuboid {
+    pub x_start: i64,
+    pub x_end: i64,
+    pub y_start: i64,
+    pub y_end: i64,
+    pub z_start: i64,
+    pub z_end: i64,
+}
+
+impl Cuboid {
+    /// Returns whether the cuboid lies completely within `other`.
+    pub fn is_in(&self, other: &Cuboid) -> bool {
+        other.x_start <= self.x_start
+            && self.x_end <= other.x_end
+            && other.y_start <= self.y_start
+            && self.y_end <= other.y_end
+            && other.z_start <= self.z_start
+            && self.z_end <= other.z_end
+    }
+}
+
+/// Largest distance of a cube from the origin along any axis, small enough for the volume
+/// of the region holding all cuboids to fit into an `i64`.
+pub const MAX_COORDINATE: i64 = 1_000_000;
+
+/// Initialization procedure region.
+pub static CORE: Cuboid = Cuboid {
+    x_start: -50,
+    x_end: 50,
+    y_start: -50,
+    y_end: 50,
+    z_start: -50,
+    z_end: 50,
+};
+
+/// Reboot step turning the cubes of a cuboid on or off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CuboidToggle(pub bool, pub Cuboid);
+
+impl CuboidToggle {
+    /// Parses an `on x=a..b,y=c..d,z=e..f` line, with coordinates up to
+    /// [`MAX_COORDINATE`] away from the origin.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let range = |parser: &mut Parser| -> Result<(i64, i64), ParseError> {
+            let rest = parser.rest();
+            let (start, end) = parser.range()?;
+            let range = &rest[..rest.len() - parser.rest().len()];
+            if start > end {
+                return Err(ParseError::at(text, range, "range ends before it starts"));
+            }
+            if start < -MAX_COORDINATE || end > MAX_COORDINATE {
+                let message = format!("coordinates must be within ±{}", MAX_COORDINATE);
+                return Err(ParseError::at(text, range, &message));
+            }
+            Ok((start, end))
+        };
+
+        let mut parser = Parser::new(text);
+        let toggle = parser.one_of(&["on", "off"])? == "on";
+        parser.tag(" x=")?;
+        let (x_start, x_end) = range(&mut parser)?;
+        parser.tag(",y=")?;
+        let (y_start, y_end) = range(&mut parser)?;
+        parser.tag(",z=")?;
+        let (z_start, z_end) = range(&mut parser)?;
+        parser.end()?;
+
+        Ok(CuboidToggle(
+            toggle,
+            Cuboid {
+                x_start,
+                x_end,
+                y_start,
+                y_end,
+                z_start,
+                z_end,
+            },
+        ))
+    }
+}
+
+impl fmt::Display for CuboidToggle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let CuboidToggle(toggle, c) = self;
+        write!(
+            f,
+            "{} x={}..{},y={}..{},z={}..{}",
+            if *toggle { "on" } else { "off" },
+            c.x_start,
+            c.x_end,
+            c.y_start,
+            c.y_end,
+            c.z_start,
+            c.z_end
+        )
+    }
+}
+
+/// Reads the reboot steps from `input`.
+pub fn get_input(input: &Input) -> Result<Vec<CuboidToggle>, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses one reboot step per line.
+pub fn parse_input(text: &str) -> Result<Vec<CuboidToggle>, ParseError> {
+    parse_lines(text.trim(), CuboidToggle::parse)
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day22>();
+}
+
+#[test]
+fn test_small_example() {
+    let toggles: Vec<CuboidToggle> = parse_input(&common::fixtures::fixture(22, "small"))
+        .unwrap()
+        .into_iter()
+        .filter(|CuboidToggle(_, cuboid)| cuboid.is_in(&CORE))
+        .collect();
+    assert_eq!(count_on(&toggles), 39);
+}
+
+#[test]
+fn test_larger_example() {
+    let toggles: Vec<CuboidToggle> = parse_input(&common::fixtures::fixture(22, "larger"))
+        .unwrap()
+        .into_iter()
+        .filter(|CuboidToggle(_, cuboid)| cuboid.is_in(&CORE))
+        .collect();
+    assert_eq!(count_on(&toggles), 590784);
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = CuboidToggle::parse("on x=10..12,y=12..10,z=10..12").unwrap_err();
+    assert_eq!((err.column, err.text.as_str()), (15, "12..10"));
+    assert_eq!(err.message, "range ends before it starts");
+    let err = CuboidToggle::parse("off x=0..1,y=0..1,z=-1000001..0").unwrap_err();
+    assert_eq!(err.message, "coordinates must be within ±1000000");
+
+    let extreme = "on x=-1000000..1000000,y=-1000000..1000000,z=-1000000..1000000";
+    assert_eq!(count_on(&parse_input(extreme).unwrap()), 2000001i64.pow(3));
+}
+
+#[test]
+fn test_all() {
+    let toggles = parse_input(&common::fixtures::fixture(22, "reboot")).unwrap();
+    assert_eq!(count_on(&toggles), 2758514936282235);
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_display_round_trip(
+        toggle: bool,
+        x in (-MAX_COORDINATE..=MAX_COORDINATE, -MAX_COORDINATE..=MAX_COORDINATE),
+        y in (-MAX_COORDINATE..=MAX_COORDINATE, -MAX_COORDINATE..=MAX_COORDINATE),
+        z in (-MAX_COORDINATE..=MAX_COORDINATE, -MAX_COORDINATE..=MAX_COORDINATE),
+    ) {
+        let (x_start, x_end) = (x.0.min(x.1), x.0.max(x.1));
+        let (y_start, y_end) = (y.0.min(y.1), y.0.max(y.1));
+        let (z_start, z_end) = (z.0.min(z.1), z.0.max(z.1));
+        let step = CuboidToggle(
+            toggle,
+            Cuboid { x_start, x_end, y_start, y_end, z_start, z_end },
+        );
+        proptest::prop_assert_eq!(CuboidToggle::parse(&step.to_string()), Ok(step));
+    }
+
+    #[test]
+    fn test_parse_never_panics(text in "(on|off) [xyz=0-9.,-]{0,32}|\\PC{0,16}") {
+        let _ = parse_input(&text);
+    }
+
+    #[test]
+    fn test_count_never_panics(
+        toggles in proptest::collection::vec(
+            (
+                proptest::bool::ANY,
+                proptest::array::uniform3((
+                    -MAX_COORDINATE - 2..=MAX_COORDINATE,
+                    -1..=MAX_COORDINATE + 2,
+                )),
+            ),
+            1..4,
+        ),
+    ) {
+        // Starts and sizes around the limits, some of them reversed or out of range
+        let text: String = toggles
+            .iter()
+            .map(|(on, [(x, w), (y, h), (z, d)])| {
+                let toggle = if *on { "on" } else { "off" };
+                format!(
+                    "{} x={}..{},y={}..{},z={}..{}\n",
+                    toggle,
+                    x,
+                    x + w,
+                    y,
+                    y + h,
+                    z,
+                    z + d
+                )
+            })
+            .collect();
+        if let Ok(toggles) = parse_input(&text) {
+            count_on(&toggles);
+            draw::drawing(&text).unwrap();
+        }
+    }
+}
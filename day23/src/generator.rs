@@ -0,0 +1,26 @@
+//! Random starting positions.
+
+use crate::{organize, unfold, Amphipod, Burrow, HALLWAY_LENGTH};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generates a burrow with two amphipods of each type shuffled into its rooms, retrying
+/// until both the folded and unfolded burrow can be organized. The burrow has no size to
+/// scale, so `scale` is ignored.
+pub fn generate<R: Rng>(rng: &mut R, _scale: usize) -> String {
+    loop {
+        let mut amphipods: Vec<Amphipod> = Amphipod::ALL.iter().flat_map(|&a| [a, a]).collect();
+        amphipods.shuffle(rng);
+
+        let burrow = Burrow {
+            hallway: [None; HALLWAY_LENGTH],
+            rooms: [0, 1, 2, 3].map(|room| vec![Some(amphipods[room]), Some(amphipods[room + 4])]),
+        };
+        if burrow.is_organized() || organize(&burrow).is_none() {
+            continue;
+        }
+        if organize(&unfold(&burrow)).is_some() {
+            return burrow.to_string();
+        }
+    }
+}
@@ -0,0 +1,341 @@
+//! Day 23: Amphipod
+
+use common::search::lowest_cost;
+use common::{Input, ParseError, Parser, Solution};
+use std::error::Error;
+use std::fmt;
+
+pub mod generator;
+
+/// Organizes the amphipods into their rooms using the least energy.
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Burrow;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(text: &str) -> Result<Burrow, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(burrow: &Burrow) -> u64 {
+        organize(burrow).expect("failed to organize amphipods")
+    }
+
+    fn part2(burrow: &Burrow) -> u64 {
+        organize(&unfold(burrow)).expect("failed to organize amphipods")
+    }
+}
+
+/// Number of spaces in the hallway.
+pub const HALLWAY_LENGTH: usize = 11;
+
+/// Number of side rooms, one for each type of amphipod.
+pub const ROOMS: usize = 4;
+
+/// Type of an amphipod, which is also the room it belongs in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Amphipod {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Amphipod {
+    /// All types, in the order of their rooms.
+    pub const ALL: [Amphipod; ROOMS] = [
+        Amphipod::Amber,
+        Amphipod::Bronze,
+        Amphipod::Copper,
+        Amphipod::Desert,
+    ];
+
+    /// Returns the amphipod written as `c`.
+    pub fn from_char(c: char) -> Option<Amphipod> {
+        match c {
+            'A' => Some(Amphipod::Amber),
+            'B' => Some(Amphipod::Bronze),
+            'C' => Some(Amphipod::Copper),
+            'D' => Some(Amphipod::Desert),
+            _ => None,
+        }
+    }
+
+    /// Returns the letter the amphipod is written as.
+    pub fn to_char(self) -> char {
+        match self {
+            Amphipod::Amber => 'A',
+            Amphipod::Bronze => 'B',
+            Amphipod::Copper => 'C',
+            Amphipod::Desert => 'D',
+        }
+    }
+
+    /// Returns the energy the amphipod needs for a single step.
+    pub fn energy(self) -> u64 {
+        match self {
+            Amphipod::Amber => 1,
+            Amphipod::Bronze => 10,
+            Amphipod::Copper => 100,
+            Amphipod::Desert => 1000,
+        }
+    }
+
+    /// Returns the index of the room the amphipod belongs in.
+    pub fn room(self) -> usize {
+        self as usize
+    }
+}
+
+/// Positions of the amphipods in the hallway and the side rooms. Each room lists its
+/// spaces from the hallway down.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Burrow {
+    pub hallway: [Option<Amphipod>; HALLWAY_LENGTH],
+    pub rooms: [Vec<Option<Amphipod>>; ROOMS],
+}
+
+/// Returns the position in the hallway just outside of `room`.
+pub fn entrance(room: usize) -> usize {
+    2 + 2 * room
+}
+
+impl Burrow {
+    /// Returns the number of spaces in each room.
+    pub fn depth(&self) -> usize {
+        self.rooms[0].len()
+    }
+
+    /// Checks whether every amphipod is in its own room.
+    pub fn is_organized(&self) -> bool {
+        self.rooms.iter().enumerate().all(|(i, room)| {
+            room.iter()
+                .all(|&space| space.is_some_and(|a| a.room() == i))
+        })
+    }
+
+    /// Checks whether `room` only contains amphipods that belong there, so they never have
+    /// to leave it again.
+    fn is_settled(&self, room: usize) -> bool {
+        self.rooms[room]
+            .iter()
+            .all(|&space| space.is_none_or(|a| a.room() == room))
+    }
+
+    /// Checks whether the hallway is free between `from` and `to`, not including `from`.
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let range = if from < to {
+            from + 1..=to
+        } else {
+            to..=from - 1
+        };
+        return self.hallway[range].iter().all(Option::is_none);
+    }
+
+    /// Returns the burrows reachable with a single move of an amphipod, together with the
+    /// energy spent on the move. Amphipods move from the top of a room into the hallway,
+    /// never stopping outside of a room, or from the hallway to the deepest free space of
+    /// their own room, once it contains no other amphipods.
+    pub fn moves(&self) -> Vec<(Burrow, u64)> {
+        let mut moves = Vec::new();
+
+        // Moving into its room is never worse than any other move, so it is taken alone
+        for (x, space) in self.hallway.iter().enumerate() {
+            let Some(amphipod) = *space else {
+                continue;
+            };
+            let room = amphipod.room();
+            if !self.is_settled(room) || !self.is_clear(x, entrance(room)) {
+                continue;
+            }
+            let depth = self.rooms[room]
+                .iter()
+                .rposition(Option::is_none)
+                .expect("room has space");
+            let steps = x.abs_diff(entrance(room)) + depth + 1;
+
+            let mut next = self.clone();
+            next.hallway[x] = None;
+            next.rooms[room][depth] = Some(amphipod);
+            return vec![(next, steps as u64 * amphipod.energy())];
+        }
+
+        for room in 0..ROOMS {
+            if self.is_settled(room) {
+                continue;
+            }
+            let Some(depth) = self.rooms[room].iter().position(Option::is_some) else {
+                continue;
+            };
+            let amphipod = self.rooms[room][depth].unwrap();
+
+            for x in 0..HALLWAY_LENGTH {
+                let outside_room = (0..ROOMS).any(|r| entrance(r) == x);
+                if outside_room || !self.is_clear(entrance(room), x) {
+                    continue;
+                }
+                let steps = x.abs_diff(entrance(room)) + depth + 1;
+
+                let mut next = self.clone();
+                next.rooms[room][depth] = None;
+                next.hallway[x] = Some(amphipod);
+                moves.push((next, steps as u64 * amphipod.energy()));
+            }
+        }
+
+        return moves;
+    }
+}
+
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let space = |space: Option<Amphipod>| space.map_or('.', Amphipod::to_char);
+
+        writeln!(f, "{}", "#".repeat(HALLWAY_LENGTH + 2))?;
+        let hallway: String = self.hallway.iter().map(|&s| space(s)).collect();
+        writeln!(f, "#{}#", hallway)?;
+        for depth in 0..self.depth() {
+            let (edge, indent) = if depth == 0 { ("##", "") } else { ("", "  ") };
+            write!(f, "{}{}#", indent, edge)?;
+            for room in &self.rooms {
+                write!(f, "{}#", space(room[depth]))?;
+            }
+            writeln!(f, "{}", edge)?;
+        }
+        writeln!(f, "  {}", "#".repeat(2 * ROOMS + 1))
+    }
+}
+
+/// Returns the least energy needed to organize the amphipods, or `None` if they get stuck.
+pub fn organize(burrow: &Burrow) -> Option<u64> {
+    lowest_cost(burrow.clone(), Burrow::moves, Burrow::is_organized)
+}
+
+/// Inserts the two rows of amphipods that were folded away in the diagram.
+pub fn unfold(burrow: &Burrow) -> Burrow {
+    let folded = [
+        [Amphipod::Desert, Amphipod::Desert],
+        [Amphipod::Copper, Amphipod::Bronze],
+        [Amphipod::Bronze, Amphipod::Amber],
+        [Amphipod::Amber, Amphipod::Copper],
+    ];
+
+    let mut unfolded = burrow.clone();
+    for (room, amphipods) in unfolded.rooms.iter_mut().zip(folded) {
+        let depth = room.len().min(1);
+        room.splice(depth..depth, amphipods.map(Some));
+    }
+    return unfolded;
+}
+
+/// Reads the burrow diagram from `input`.
+pub fn get_input(input: &Input) -> Result<Burrow, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses a diagram of the burrow, with an empty hallway and rooms of any depth.
+pub fn parse_input(text: &str) -> Result<Burrow, ParseError> {
+    let mut lines = text.lines();
+    let mut expect_line = |expected: &str| match lines.next() {
+        Some(line) => {
+            let mut parser = Parser::new(line);
+            parser.tag(expected)?;
+            parser.end()?;
+            Ok(())
+        }
+        None => Err(ParseError::at_end(
+            text,
+            &format!("expected `{}`", expected),
+        )),
+    };
+    expect_line("#############")?;
+    expect_line("#...........#").map_err(|e| e.below(1))?;
+
+    let mut rooms: [Vec<Option<Amphipod>>; ROOMS] = Default::default();
+    for (i, line) in text.lines().enumerate().skip(2) {
+        let mut parser = Parser::new(line);
+        if i > 2 && parser.tag("  #########").is_ok() {
+            parser.end().map_err(|e| e.below(i))?;
+            // Each room must end up full of its own type, so there are as many of each
+            let depth = rooms[0].len();
+            for amphipod in Amphipod::ALL {
+                let count = rooms
+                    .iter()
+                    .flatten()
+                    .filter(|&&a| a == Some(amphipod))
+                    .count();
+                if count != depth {
+                    return Err(ParseError::at(
+                        line,
+                        line,
+                        &format!(
+                            "expected {} amphipods of type {}, found {}",
+                            depth,
+                            amphipod.to_char(),
+                            count
+                        ),
+                    )
+                    .below(i));
+                }
+            }
+            return Ok(Burrow {
+                hallway: [None; HALLWAY_LENGTH],
+                rooms,
+            });
+        }
+
+        parser
+            .tag(if i == 2 { "###" } else { "  #" })
+            .map_err(|e| e.below(i))?;
+        for room in rooms.iter_mut() {
+            let amphipod = parser
+                .char_where(|c| Amphipod::from_char(c).is_some(), "an amphipod")
+                .map_err(|e| e.below(i))?;
+            room.push(Amphipod::from_char(amphipod));
+            parser.tag("#").map_err(|e| e.below(i))?;
+        }
+        if i == 2 {
+            parser.tag("##").map_err(|e| e.below(i))?;
+        }
+        parser.end().map_err(|e| e.below(i))?;
+    }
+
+    return Err(ParseError::at_end(text, "expected `  #########`"));
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day23>();
+}
+
+#[test]
+fn test_parse() {
+    let example = common::fixtures::fixture(23, "example");
+    let burrow = parse_input(&example).unwrap();
+    assert_eq!(burrow.to_string(), example);
+    assert_eq!(
+        burrow.rooms[1],
+        vec![Some(Amphipod::Copper), Some(Amphipod::Desert)]
+    );
+
+    let err = parse_input(&example.replace("#A#D", "#A#E")).unwrap_err();
+    assert_eq!((err.line, err.column), (4, 6));
+
+    let unbalanced = example
+        .replace("###B#C#B#D###", "###A#A#B#C###")
+        .replace("  #A#D#C#A#", "  #A#A#D#D#");
+    let err = parse_input(&unbalanced).unwrap_err();
+    assert_eq!(err.line, 5);
+    assert_eq!(err.message, "expected 2 amphipods of type A, found 4");
+}
+
+#[test]
+fn test_example() {
+    let burrow = parse_input(&common::fixtures::fixture(23, "example")).unwrap();
+    assert_eq!(organize(&burrow), Some(12521));
+    assert_eq!(organize(&unfold(&burrow)), Some(44169));
+}
@@ -0,0 +1,3 @@
+fn main() {
+    common::run::<day23::Day23>();
+}
@@ -0,0 +1,302 @@
+//! Interpreter for programs of the arithmetic logic unit.
+
+use common::{parse_lines, ParseError, Parser};
+use std::fmt;
+
+/// One of the four variables of the ALU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Register {
+    const NAMES: [&'static str; 4] = ["w", "x", "y", "z"];
+
+    fn parse(parser: &mut Parser) -> Result<Register, ParseError> {
+        match parser.one_of(&Register::NAMES)? {
+            "w" => Ok(Register::W),
+            "x" => Ok(Register::X),
+            "y" => Ok(Register::Y),
+            _ => Ok(Register::Z),
+        }
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Register::NAMES[*self as usize])
+    }
+}
+
+/// Second operand of an instruction, a variable or a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Number(i64),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Register(register) => write!(f, "{}", register),
+            Operand::Number(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+/// Single instruction, storing its result in the first operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, a, b) = match *self {
+            Instruction::Inp(a) => return write!(f, "inp {}", a),
+            Instruction::Add(a, b) => ("add", a, b),
+            Instruction::Mul(a, b) => ("mul", a, b),
+            Instruction::Div(a, b) => ("div", a, b),
+            Instruction::Mod(a, b) => ("mod", a, b),
+            Instruction::Eql(a, b) => ("eql", a, b),
+        };
+        write!(f, "{} {} {}", name, a, b)
+    }
+}
+
+/// Reason the ALU stopped executing a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AluError {
+    /// An `inp` instruction was executed after all inputs were read.
+    MissingInput,
+    /// A `div` instruction divided by zero.
+    DivisionByZero,
+    /// A `mod` instruction with a negative dividend or a divisor that is not positive.
+    InvalidModulo,
+    /// The result of an instruction does not fit in a variable.
+    Overflow,
+}
+
+impl fmt::Display for AluError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AluError::MissingInput => write!(f, "no input left to read"),
+            AluError::DivisionByZero => write!(f, "division by zero"),
+            AluError::InvalidModulo => {
+                write!(f, "modulo of a negative number or by a number below one")
+            }
+            AluError::Overflow => write!(f, "result out of range"),
+        }
+    }
+}
+
+impl std::error::Error for AluError {}
+
+/// State of the ALU, the values of its four variables.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Alu {
+    pub registers: [i64; 4],
+}
+
+impl Alu {
+    /// Returns the value of `register`.
+    pub fn get(&self, register: Register) -> i64 {
+        self.registers[register as usize]
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.get(register),
+            Operand::Number(number) => number,
+        }
+    }
+
+    /// Executes `instruction`, taking the next of `inputs` for `inp`.
+    pub fn execute<I>(&mut self, instruction: &Instruction, inputs: &mut I) -> Result<(), AluError>
+    where
+        I: Iterator<Item = i64>,
+    {
+        let (register, result) = match *instruction {
+            Instruction::Inp(a) => (a, inputs.next().ok_or(AluError::MissingInput)?),
+            Instruction::Add(a, b) => (
+                a,
+                self.get(a)
+                    .checked_add(self.value(b))
+                    .ok_or(AluError::Overflow)?,
+            ),
+            Instruction::Mul(a, b) => (
+                a,
+                self.get(a)
+                    .checked_mul(self.value(b))
+                    .ok_or(AluError::Overflow)?,
+            ),
+            Instruction::Div(a, b) => {
+                let divisor = self.value(b);
+                if divisor == 0 {
+                    return Err(AluError::DivisionByZero);
+                }
+                (
+                    a,
+                    self.get(a).checked_div(divisor).ok_or(AluError::Overflow)?,
+                )
+            }
+            Instruction::Mod(a, b) => {
+                let (dividend, divisor) = (self.get(a), self.value(b));
+                if dividend < 0 || divisor <= 0 {
+                    return Err(AluError::InvalidModulo);
+                }
+                (a, dividend % divisor)
+            }
+            Instruction::Eql(a, b) => (a, (self.get(a) == self.value(b)) as i64),
+        };
+        self.registers[register as usize] = result;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Alu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let values: Vec<String> = Register::NAMES
+            .iter()
+            .zip(self.registers)
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        write!(f, "{}", values.join(" "))
+    }
+}
+
+/// Runs `program` on a fresh ALU with `inputs` and returns the final state.
+pub fn run(program: &[Instruction], inputs: &[i64]) -> Result<Alu, AluError> {
+    trace(program, inputs, |_, _| {})
+}
+
+/// Runs `program` like [`run`], calling `step` with each instruction and the state after
+/// executing it.
+pub fn trace<F>(program: &[Instruction], inputs: &[i64], mut step: F) -> Result<Alu, AluError>
+where
+    F: FnMut(&Instruction, &Alu),
+{
+    let mut alu = Alu::default();
+    let mut inputs = inputs.iter().copied();
+    for instruction in program {
+        alu.execute(instruction, &mut inputs)?;
+        step(instruction, &alu);
+    }
+    return Ok(alu);
+}
+
+/// Runs `program` like [`trace`], calling `print` with each instruction and the state
+/// after executing it if `traced`, and then with the final state.
+pub fn report<F>(
+    program: &[Instruction],
+    inputs: &[i64],
+    traced: bool,
+    mut print: F,
+) -> Result<Alu, AluError>
+where
+    F: FnMut(String),
+{
+    let alu = trace(program, inputs, |instruction, alu| {
+        if traced {
+            print(format!("{:<12} {}", instruction.to_string(), alu));
+        }
+    })?;
+    print(alu.to_string());
+    return Ok(alu);
+}
+
+/// Parses inputs given as a string of digits like `13579`, one input per digit.
+pub fn parse_digits(text: &str) -> Result<Vec<i64>, ParseError> {
+    text.char_indices()
+        .map(|(i, c)| match c.to_digit(10) {
+            Some(digit) => Ok(digit as i64),
+            None => Err(ParseError::at(
+                text,
+                &text[i..i + c.len_utf8()],
+                "expected a digit",
+            )),
+        })
+        .collect()
+}
+
+/// Parses a program with one instruction like `add x -5` or `inp w` per line.
+pub fn parse_program(text: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(text.trim_end(), |line| {
+        let mut parser = Parser::new(line);
+        let name = parser.one_of(&["inp", "add", "mul", "div", "mod", "eql"])?;
+        parser.tag(" ")?;
+        let a = Register::parse(&mut parser)?;
+        if name == "inp" {
+            parser.end()?;
+            return Ok(Instruction::Inp(a));
+        }
+
+        parser.tag(" ")?;
+        let b = if parser
+            .rest()
+            .starts_with(|c: char| c == '-' || c.is_ascii_digit())
+        {
+            Operand::Number(parser.number()?)
+        } else {
+            Operand::Register(Register::parse(&mut parser)?)
+        };
+        parser.end()?;
+
+        Ok(match name {
+            "add" => Instruction::Add(a, b),
+            "mul" => Instruction::Mul(a, b),
+            "div" => Instruction::Div(a, b),
+            "mod" => Instruction::Mod(a, b),
+            _ => Instruction::Eql(a, b),
+        })
+    })
+}
+
+#[test]
+fn test_run() {
+    let program = parse_program(
+        "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\ndiv w 2\nmod w 2\n",
+    )
+    .unwrap();
+    assert_eq!(run(&program, &[13]).unwrap().registers, [1, 1, 0, 1]);
+    assert_eq!(run(&program, &[]), Err(AluError::MissingInput));
+
+    let lines: Vec<String> = program.iter().map(|i| i.to_string()).collect();
+    assert_eq!(lines[1], "add z w");
+    assert_eq!(
+        parse_program("mul x -3").unwrap()[0].to_string(),
+        "mul x -3"
+    );
+    assert_eq!(parse_program("mul x").unwrap_err().column, 6);
+}
+
+#[test]
+fn test_report() {
+    let program = parse_program("inp x\ninp y\nmul x y\nadd z x\ndiv z y\n").unwrap();
+    let inputs = parse_digits("63").unwrap();
+    let mut lines = Vec::new();
+    let alu = report(&program, &inputs, true, |line| lines.push(line)).unwrap();
+    assert_eq!(alu.registers, [0, 18, 3, 6]);
+    assert_eq!(lines.len(), 6);
+    assert_eq!(lines[2], "mul x y      w=0 x=18 y=3 z=0");
+    assert_eq!(lines[5], "w=0 x=18 y=3 z=6");
+
+    let mut lines = Vec::new();
+    assert_eq!(
+        report(&program, &[1, 0], false, |line| lines.push(line)),
+        Err(AluError::DivisionByZero)
+    );
+    assert!(lines.is_empty());
+
+    let program = parse_program("add x 9223372036854775807\nadd x 1").unwrap();
+    assert_eq!(run(&program, &[]), Err(AluError::Overflow));
+    assert_eq!(parse_digits("12a").unwrap_err().column, 3);
+}
@@ -0,0 +1,45 @@
+//! Random MONAD programs.
+
+use crate::BLOCK;
+use rand::Rng;
+
+/// Number of digits of a model number.
+const DIGITS: usize = 14;
+
+/// Generates a MONAD with a block for each of the 14 digits. Half of the blocks push their
+/// digit and the other half pop one, nested like parentheses, with each pair of digits
+/// having a satisfiable difference. The program has no size to scale, so `scale` is
+/// ignored.
+pub fn generate<R: Rng>(rng: &mut R, _scale: usize) -> String {
+    let mut program = String::new();
+    let mut stack = Vec::new();
+    let mut pushed = 0;
+    for _ in 0..DIGITS {
+        let push = pushed < DIGITS / 2 && (stack.is_empty() || rng.gen_bool(0.5));
+        let (divisor, check, offset) = if push {
+            pushed += 1;
+            let offset = rng.gen_range(1..=16);
+            stack.push(offset);
+            (1, rng.gen_range(10..=15), offset)
+        } else {
+            let pushed_offset = stack.pop().expect("a digit was pushed");
+            let difference: i64 = rng.gen_range(-8..=8);
+            (26, difference - pushed_offset, rng.gen_range(1..=16))
+        };
+
+        for (i, line) in BLOCK.lines().enumerate() {
+            let parameter = match i {
+                4 => divisor,
+                5 => check,
+                15 => offset,
+                _ => {
+                    program += &format!("{}\n", line);
+                    continue;
+                }
+            };
+            let instruction = line.strip_suffix(" 0").expect("parameter is a zero");
+            program += &format!("{} {}\n", instruction, parameter);
+        }
+    }
+    return program;
+}
@@ -0,0 +1,204 @@
+//! Day 24: Arithmetic Logic Unit
+
+use alu::{Instruction, Operand, Register};
+use common::{Input, ParseError, Solution};
+use std::error::Error;
+
+pub mod alu;
+pub mod generator;
+
+/// Finds the model numbers accepted by the MONAD program.
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Instruction>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(text: &str) -> Result<Vec<Instruction>, ParseError> {
+        alu::parse_program(text)
+    }
+
+    fn part1(program: &Vec<Instruction>) -> u64 {
+        let constraints = analyze(program).expect("program is not a MONAD");
+        model_number(&constraints, 9).expect("no model number is accepted")
+    }
+
+    fn part2(program: &Vec<Instruction>) -> u64 {
+        let constraints = analyze(program).expect("program is not a MONAD");
+        model_number(&constraints, 1).expect("no model number is accepted")
+    }
+}
+
+/// Parameters of the block of MONAD checking a single digit. The block keeps a stack of
+/// base 26 numbers in `z`. It compares the digit with the top of the stack plus `check`,
+/// pops the top if `divisor` is 26 and pushes the digit plus `offset` if the comparison
+/// fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub divisor: i64,
+    pub check: i64,
+    pub offset: i64,
+}
+
+/// Instructions of each block, with zeros in place of the parameters.
+pub(crate) const BLOCK: &str = "inp w
+mul x 0
+add x z
+mod x 26
+div z 0
+add x 0
+eql x w
+eql x 0
+mul y 0
+add y 25
+mul y x
+add y 1
+mul z y
+mul y 0
+add y w
+add y 0
+mul y x
+add z y";
+
+/// Splits `program` into its blocks, or returns `None` if it is not made of one or more
+/// blocks like the ones of MONAD.
+pub fn blocks(program: &[Instruction]) -> Option<Vec<Block>> {
+    let template = alu::parse_program(BLOCK).expect("block template is valid");
+    if program.is_empty() || !program.len().is_multiple_of(template.len()) {
+        return None;
+    }
+
+    let parameter = |instruction: &Instruction, register: Register| match *instruction {
+        Instruction::Div(r, Operand::Number(n)) | Instruction::Add(r, Operand::Number(n))
+            if r == register =>
+        {
+            Some(n)
+        }
+        _ => None,
+    };
+
+    program
+        .chunks(template.len())
+        .map(|block| {
+            for (i, (instruction, expected)) in block.iter().zip(&template).enumerate() {
+                if ![4, 5, 15].contains(&i) && instruction != expected {
+                    return None;
+                }
+            }
+            Some(Block {
+                divisor: parameter(&block[4], Register::Z)?,
+                check: parameter(&block[5], Register::X)?,
+                offset: parameter(&block[15], Register::Y)?,
+            })
+        })
+        .collect()
+}
+
+/// Requirement that the digit at index `second` equals the one at index `first` plus
+/// `difference`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Constraint {
+    pub first: usize,
+    pub second: usize,
+    pub difference: i64,
+}
+
+/// Derives the constraints a model number has to satisfy to leave `z` at zero. Every
+/// block pushing a digit has to be matched by a later block popping it, with the digits
+/// passing that block's comparison. Returns `None` if `program` is not a MONAD or cannot
+/// leave `z` at zero.
+pub fn analyze(program: &[Instruction]) -> Option<Vec<Constraint>> {
+    let mut stack = Vec::new();
+    let mut constraints = Vec::new();
+    for (i, block) in blocks(program)?.into_iter().enumerate() {
+        match block.divisor {
+            // No digit passes the comparison, so the digit is always pushed
+            1 if block.check > 9 => stack.push((i, block.offset)),
+            26 => {
+                let (first, offset) = stack.pop()?;
+                constraints.push(Constraint {
+                    first,
+                    second: i,
+                    difference: offset + block.check,
+                });
+            }
+            _ => return None,
+        }
+    }
+
+    if !stack.is_empty() {
+        return None;
+    }
+    constraints.sort_by_key(|c| c.first);
+    return Some(constraints);
+}
+
+/// Returns the model number satisfying `constraints` whose digits are closest to
+/// `preferred`, which is 9 for the largest and 1 for the smallest, or `None` if the
+/// constraints cannot be satisfied.
+pub fn model_number(constraints: &[Constraint], preferred: i64) -> Option<u64> {
+    let mut digits = vec![0; 2 * constraints.len()];
+    for c in constraints {
+        if c.difference.abs() > 8 {
+            return None;
+        }
+        let first = preferred.clamp(1 - c.difference.min(0), 9 - c.difference.max(0));
+        digits[c.first] = first;
+        digits[c.second] = first + c.difference;
+    }
+    return Some(digits.iter().fold(0, |number, &d| 10 * number + d as u64));
+}
+
+/// Reads the MONAD program from `input`.
+pub fn get_input(input: &Input) -> Result<Vec<Instruction>, Box<dyn Error>> {
+    Ok(alu::parse_program(&input.read()?)?)
+}
+
+#[test]
+fn test_model_number() {
+    let constraints = [
+        Constraint {
+            first: 0,
+            second: 3,
+            difference: -2,
+        },
+        Constraint {
+            first: 1,
+            second: 2,
+            difference: 5,
+        },
+    ];
+    assert_eq!(model_number(&constraints, 9), Some(9497));
+    assert_eq!(model_number(&constraints, 1), Some(3161));
+}
+
+#[test]
+fn test_generated() {
+    use rand::SeedableRng;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(24);
+    let program = alu::parse_program(&generator::generate(&mut rng, 1)).unwrap();
+    let constraints = analyze(&program).unwrap();
+    for preferred in [1, 9] {
+        let number = model_number(&constraints, preferred).unwrap();
+        let digits: Vec<i64> = number
+            .to_string()
+            .bytes()
+            .map(|b| (b - b'0') as i64)
+            .collect();
+        assert_eq!(digits.len(), 14);
+        assert_eq!(alu::run(&program, &digits).unwrap().get(Register::Z), 0);
+
+        // Changing any digit towards the preferred one breaks a constraint
+        for i in 0..digits.len() {
+            let mut changed = digits.clone();
+            changed[i] += (preferred - changed[i]).signum();
+            if changed != digits {
+                assert_ne!(alu::run(&program, &changed).unwrap().get(Register::Z), 0);
+            }
+        }
+    }
+}
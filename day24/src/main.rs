@@ -0,0 +1,31 @@
+use common::flag_value;
+use common::solution::load;
+use day24::alu::{self, parse_digits};
+
+/// Runs the program on the digits of `digits` as inputs and prints the final state of
+/// the ALU, preceded with each instruction and the state after it if `traced`.
+fn run_program(digits: &str, traced: bool) {
+    let inputs = match parse_digits(digits) {
+        Ok(inputs) => inputs,
+        Err(err) => {
+            eprintln!("error: invalid inputs: {}", err.diagnostic(digits));
+            std::process::exit(1);
+        }
+    };
+    let program = load(24, alu::parse_program);
+
+    if let Err(err) = alu::report(&program, &inputs, traced, |line| println!("{}", line)) {
+        eprintln!("error: program failed: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn main() {
+    if let Some(digits) = flag_value("trace") {
+        run_program(&digits, true);
+    } else if let Some(digits) = flag_value("run") {
+        run_program(&digits, false);
+    } else {
+        common::run::<day24::Day24>();
+    }
+}
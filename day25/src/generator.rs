@@ -0,0 +1,26 @@
+//! Random sea floors.
+
+use crate::{step, Cell, SeaFloor};
+use grid::Grid;
+use rand::Rng;
+
+/// Steps within which the sea cucumbers of a generated sea floor have to stop moving.
+const MAX_STEPS: usize = 2000;
+
+/// Generates a `139 * scale` by `137 * scale` sea floor with about a quarter of it covered
+/// by each herd. Sea floors on which the herds keep moving past [`MAX_STEPS`] are drawn
+/// again, as some of them never stop.
+pub fn generate<R: Rng>(rng: &mut R, scale: usize) -> String {
+    let (width, height) = (139 * scale as i32, 137 * scale as i32);
+    loop {
+        let floor: SeaFloor = Grid::from_fn(width, height, |_, _| match rng.gen_range(0..4) {
+            0 => Cell::East,
+            1 => Cell::South,
+            _ => Cell::Empty,
+        });
+        let mut simulated = floor.clone();
+        if (0..MAX_STEPS).any(|_| step(&mut simulated) == 0) {
+            return floor.to_string();
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Day 25: Sea Cucumber
+
+use common::{Input, ParseError, Solution};
+use grid::Grid;
+use std::error::Error;
+use std::fmt;
+
+pub mod generator;
+
+/// Simulates the herds of sea cucumbers until they stop moving.
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = SeaFloor;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(text: &str) -> Result<SeaFloor, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(floor: &SeaFloor) -> usize {
+        simulate_until_stopped(&mut floor.clone())
+    }
+
+    /// There is no second puzzle, the last star is awarded for collecting all others.
+    fn part2(_: &SeaFloor) -> String {
+        "Merry Christmas!".to_owned()
+    }
+}
+
+/// Content of a location on the sea floor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    East,
+    South,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::East => '>',
+            Cell::South => 'v',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+/// Sea cucumbers on the sea floor, which wraps around at its edges.
+pub type SeaFloor = Grid<Cell>;
+
+/// Moves every sea cucumber of `herd` that faces an empty location, all at once, and
+/// returns how many moved.
+fn move_herd(floor: &mut SeaFloor, herd: Cell, (dx, dy): (i32, i32)) -> usize {
+    let (width, height) = (floor.width(), floor.height());
+    let target = |x: i32, y: i32| ((x + dx) % width, (y + dy) % height);
+    let moving: Vec<_> = floor
+        .positions()
+        .filter(|&(x, y)| floor[(x, y)] == herd && floor[target(x, y)] == Cell::Empty)
+        .collect();
+
+    for &(x, y) in &moving {
+        let next = target(x, y);
+        floor[(x, y)] = Cell::Empty;
+        floor[next] = herd;
+    }
+    return moving.len();
+}
+
+/// Advances the simulation by one step, moving the east-facing herd before the
+/// south-facing one, and returns the number of sea cucumbers that moved.
+pub fn step(floor: &mut SeaFloor) -> usize {
+    move_herd(floor, Cell::East, (1, 0)) + move_herd(floor, Cell::South, (0, 1))
+}
+
+/// Runs steps until no sea cucumber moves and returns that step.
+pub fn simulate_until_stopped(floor: &mut SeaFloor) -> usize {
+    let mut steps = 0;
+    loop {
+        steps += 1;
+        if step(floor) == 0 {
+            return steps;
+        }
+    }
+}
+
+/// Reads the sea floor from `input`.
+pub fn get_input(input: &Input) -> Result<SeaFloor, Box<dyn Error>> {
+    Ok(parse_input(&input.read()?)?)
+}
+
+/// Parses the sea floor, `>` and `v` being sea cucumbers facing east and south.
+pub fn parse_input(text: &str) -> Result<SeaFloor, ParseError> {
+    Grid::parse(text, |c| match c {
+        '.' => Some(Cell::Empty),
+        '>' => Some(Cell::East),
+        'v' => Some(Cell::South),
+        _ => None,
+    })
+}
+
+#[test]
+fn test_fixtures() {
+    common::fixtures::check::<Day25>();
+}
+
+#[test]
+fn test_simulate() {
+    let mut floor = parse_input(&common::fixtures::fixture(25, "example")).unwrap();
+    let mut stepped = floor.clone();
+    step(&mut stepped);
+    assert_eq!(
+        stepped.to_string(),
+        "....>.>v.>
+v.v>.>v.v.
+>v>>..>v..
+>>v>v>.>.v
+.>v.v...v.
+v>>.>vvv..
+..v...>>..
+vv...>>vv.
+>.v.v..v.v
+"
+    );
+    assert_eq!(simulate_until_stopped(&mut floor), 58);
+}
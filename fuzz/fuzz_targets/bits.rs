@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Ok(mut reader) = day16::parse_input(text) {
+        let _ = day16::parse_packet(&mut reader);
+    }
+});
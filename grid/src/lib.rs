@@ -0,0 +1,321 @@
+//! Two-dimensional grid of cells as used by the map and image puzzles.
+
+use common::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Offsets of the four orthogonal neighbours of a cell.
+pub const NEIGHBOURS_4: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the eight orthogonal and diagonal neighbours of a cell.
+pub const NEIGHBOURS_8: [(i32, i32); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Rectangular grid of cells stored row by row, addressed by `(x, y)` with `(0, 0)` at the
+/// top left.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: i32,
+    height: i32,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row-major order.
+    pub fn from_cells(width: i32, height: i32, cells: Vec<T>) -> Grid<T> {
+        assert!(width >= 0 && height >= 0, "negative grid size");
+        assert_eq!(
+            cells.len(),
+            (width * height) as usize,
+            "wrong number of cells"
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid by calling `f` with the position of every cell.
+    pub fn from_fn<F>(width: i32, height: i32, mut f: F) -> Grid<T>
+    where
+        F: FnMut(i32, i32) -> T,
+    {
+        let mut cells = Vec::with_capacity((width.max(0) * height.max(0)) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(x, y));
+            }
+        }
+        Grid::from_cells(width, height, cells)
+    }
+
+    /// Creates a grid from the lines of `text`, ignoring surrounding whitespace, converting
+    /// each character with `f`. Fails on characters `f` returns `None` for and on lines
+    /// differing in length.
+    pub fn parse<F>(text: &str, mut f: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut height = 0;
+        let mut width = None;
+        let mut cells = Vec::new();
+
+        // The lines are slices of `text`, so errors point at their position in all of it
+        for line in text.trim().lines() {
+            for (i, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    ParseError::at(text, &line[i..i + c.len_utf8()], "unexpected character")
+                })?;
+                cells.push(cell);
+            }
+
+            let length = line.chars().count();
+            if *width.get_or_insert(length) != length {
+                return Err(ParseError::at(
+                    text,
+                    line,
+                    "line differs in length from the first line",
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Grid::from_cells(width.unwrap_or(0) as i32, height, cells))
+    }
+
+    /// Returns the number of columns.
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Returns the number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns whether the grid has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns whether `(x, y)` lies within the grid.
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= 0 && y >= 0 && x < self.width && y < self.height
+    }
+
+    fn index_of(&self, x: i32, y: i32) -> Option<usize> {
+        if self.contains(x, y) {
+            Some((y * self.width + x) as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at `(x, y)` or `None` if it lies outside the grid.
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    /// Returns the cell at `(x, y)` mutably or `None` if it lies outside the grid.
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        self.index_of(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    /// Iterates over the positions of all cells in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterates over all cells in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Iterates mutably over all cells in row-major order.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Iterates over the positions of the orthogonal neighbours of `(x, y)` within the grid.
+    pub fn neighbours4(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Iterates over the positions of the orthogonal and diagonal neighbours of `(x, y)`
+    /// within the grid.
+    pub fn neighbours8(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        x: i32,
+        y: i32,
+        offsets: &'static [(i32, i32)],
+    ) -> impl Iterator<Item = (i32, i32)> + 'a {
+        offsets
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(move |&(xx, yy)| self.contains(xx, yy))
+    }
+
+    /// Returns the cells of row `y`.
+    pub fn row(&self, y: i32) -> &[T] {
+        assert!(y >= 0 && y < self.height, "row {} out of bounds", y);
+        let start = (y * self.width) as usize;
+        &self.cells[start..start + self.width as usize]
+    }
+
+    /// Iterates over all rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// Iterates over the cells of column `x` from top to bottom.
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        assert!(x >= 0 && x < self.width, "column {} out of bounds", x);
+        (0..self.height).map(move |y| &self[(x, y)])
+    }
+
+    /// Creates a grid of the same size by converting every cell with `f`.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::from_cells(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: i32, height: i32, value: T) -> Grid<T> {
+        Grid::from_cells(width, height, vec![value; (width * height) as usize])
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+
+    /// Rotates the grid clockwise by 90 degrees.
+    pub fn rotate_right(&self) -> Grid<T> {
+        self.transpose().flip_horizontal()
+    }
+}
+
+impl<T: From<u8>> Grid<T> {
+    /// Creates a grid from lines of single decimal digits.
+    pub fn parse_digits(text: &str) -> Result<Grid<T>, ParseError> {
+        Grid::parse(text, |c| c.to_digit(10).map(|d| T::from(d as u8)))
+    }
+}
+
+impl<T> Index<(i32, i32)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (i32, i32)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("position ({}, {}) out of bounds", x, y))
+    }
+}
+
+impl<T> IndexMut<(i32, i32)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (i32, i32)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("position ({}, {}) out of bounds", x, y))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_parse_digits() {
+    let grid = Grid::<u8>::parse_digits("123\n456\n").unwrap();
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 2);
+    assert_eq!(grid.get(2, 1), Some(&6));
+    assert_eq!(grid.get(3, 1), None);
+    assert_eq!(grid.get(-1, 0), None);
+    assert_eq!(grid.row(1), &[4, 5, 6]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+    assert_eq!(grid.to_string(), "123\n456\n");
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = Grid::parse("##\n#", |c| Some(c == '#')).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+
+    let err = Grid::<u8>::parse_digits("12\n3x").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+
+    let err = Grid::<u8>::parse_digits("\n\n  1x\n34").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, 4, "x"));
+    let err = Grid::<u8>::parse_digits("\n\n12\n3").unwrap_err();
+    assert_eq!((err.line, err.column), (4, 1));
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = Grid::new(3, 3, 0);
+    assert_eq!(grid.neighbours4(1, 1).count(), 4);
+    assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    assert_eq!(
+        grid.neighbours4(0, 0).collect::<Vec<_>>(),
+        vec![(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbours8(2, 2).count(), 3);
+}
+
+#[test]
+fn test_transform() {
+    let grid = Grid::parse("ab\ncd\nef", Some).unwrap();
+    assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+    assert_eq!(grid.flip_horizontal().to_string(), "ba\ndc\nfe\n");
+    assert_eq!(grid.flip_vertical().to_string(), "ef\ncd\nab\n");
+    assert_eq!(grid.rotate_right().to_string(), "eca\nfdb\n");
+    assert_eq!(
+        grid.map(|c| c.to_ascii_uppercase()).to_string(),
+        "AB\nCD\nEF\n"
+    );
+}